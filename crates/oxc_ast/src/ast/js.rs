@@ -601,6 +601,10 @@ pub struct CallExpression<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
     pub arguments: Vec<'a, Argument<'a>>,
     pub optional: bool, // for optional chaining
+    /// `true` if the call is known to be free of side effects, so that it is printed with a
+    /// `/* #__PURE__ */` annotation. Only set on calls created by transforms.
+    #[serde(skip)]
+    pub pure: bool,
 }
 
 /// `new C()` in `class C {}; new C();`
@@ -187,6 +187,7 @@ const _: () = {
     assert!(offset_of!(CallExpression, type_parameters) == 24usize);
     assert!(offset_of!(CallExpression, arguments) == 32usize);
     assert!(offset_of!(CallExpression, optional) == 64usize);
+    assert!(offset_of!(CallExpression, pure) == 65usize);
 
     assert!(size_of::<NewExpression>() == 64usize);
     assert!(align_of::<NewExpression>() == 8usize);
@@ -1742,6 +1743,7 @@ const _: () = {
     assert!(offset_of!(CallExpression, type_parameters) == 16usize);
     assert!(offset_of!(CallExpression, arguments) == 20usize);
     assert!(offset_of!(CallExpression, optional) == 36usize);
+    assert!(offset_of!(CallExpression, pure) == 37usize);
 
     assert!(size_of::<NewExpression>() == 36usize);
     assert!(align_of::<NewExpression>() == 4usize);
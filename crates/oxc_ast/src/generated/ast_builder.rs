@@ -2316,6 +2316,7 @@ impl<'a> AstBuilder<'a> {
             type_parameters: type_parameters.into_in(self.allocator),
             arguments,
             optional,
+            pure: Default::default(),
         }
     }
 
@@ -715,6 +715,7 @@ impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for CallExpression<'old_alloc>
             type_parameters: CloneIn::clone_in(&self.type_parameters, allocator),
             arguments: CloneIn::clone_in(&self.arguments, allocator),
             optional: CloneIn::clone_in(&self.optional, allocator),
+            pure: CloneIn::clone_in(&self.pure, allocator),
         }
     }
 }
@@ -798,6 +798,7 @@ impl<'a> ContentEq for CallExpression<'a> {
             && ContentEq::content_eq(&self.type_parameters, &other.type_parameters)
             && ContentEq::content_eq(&self.arguments, &other.arguments)
             && ContentEq::content_eq(&self.optional, &other.optional)
+            && ContentEq::content_eq(&self.pure, &other.pure)
     }
 }
 
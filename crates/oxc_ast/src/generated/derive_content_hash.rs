@@ -372,6 +372,7 @@ impl<'a> ContentHash for CallExpression<'a> {
         ContentHash::content_hash(&self.type_parameters, state);
         ContentHash::content_hash(&self.arguments, state);
         ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.pure, state);
     }
 }
 
@@ -1387,11 +1387,20 @@ impl<'a> GenExpr for CallExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         let mut wrap = precedence >= Precedence::New || ctx.intersects(Context::FORBID_CALL);
         let annotate_comments = p.get_leading_annotate_comments(self.span.start);
-        if !annotate_comments.is_empty() && precedence >= Precedence::Postfix {
+        let print_pure = self.pure
+            && p.preserve_annotate_comments()
+            && !annotate_comments
+                .iter()
+                .any(|c| c.annotation_kind().contains(AnnotationKind::PURE));
+        if (!annotate_comments.is_empty() || print_pure) && precedence >= Precedence::Postfix {
             wrap = true;
         }
         p.wrap(wrap, |p| {
             p.print_comments(&annotate_comments, &mut AnnotationKind::empty());
+            if print_pure {
+                p.print_str("/* #__PURE__ */");
+                p.print_soft_space();
+            }
             p.add_source_mapping(self.span.start);
             self.callee.print_expr(p, Precedence::Postfix, Context::empty());
             if self.optional {
@@ -1,3 +1,9 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::CallExpression, visit::walk_mut, VisitMut};
+use oxc_codegen::{CodeGenerator, CommentOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
 use crate::tester::test;
 
 #[test]
@@ -209,3 +215,38 @@ const defineSSRCustomElement = () => {
         "const defineSSRCustomElement = () => {\n\treturn /* @__PURE__ */ /* @__NO_SIDE_EFFECTS__ */ defineCustomElement(options, extraOptions, hydrate);\n};\n",
     );
 }
+
+/// Calls marked as `pure` in the AST, e.g. by a transform, are printed with an annotation
+#[test]
+fn pure_call_expression() {
+    struct MarkPure;
+
+    impl<'a> VisitMut<'a> for MarkPure {
+        fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+            expr.pure = true;
+            walk_mut::walk_call_expression(self, expr);
+        }
+    }
+
+    let test = |source_text: &str, expected: &str| {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let mut program = ret.program;
+        MarkPure.visit_program(&mut program);
+        let result = CodeGenerator::new()
+            .enable_comment(
+                source_text,
+                ret.trivias,
+                CommentOptions { preserve_annotate_comments: true },
+            )
+            .build(&program)
+            .source_text;
+        assert_eq!(result, expected, "\nfor source {source_text:?}");
+    };
+
+    test("foo();", "/* #__PURE__ */ foo();\n");
+    test("var Foo = function() {}();", "var Foo = /* #__PURE__ */ function() {}();\n");
+    test("foo().bar();", "/* #__PURE__ */ (/* #__PURE__ */ foo()).bar();\n");
+    // The annotation is not printed twice
+    test("/* @__PURE__ */ foo();", "/* @__PURE__ */ foo();\n");
+}
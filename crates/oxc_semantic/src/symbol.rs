@@ -154,6 +154,10 @@ impl SymbolTable {
         };
     }
 
+    pub fn clear_redeclarations(&mut self, symbol_id: SymbolId) {
+        self.redeclarations[symbol_id] = None;
+    }
+
     pub fn create_reference(&mut self, reference: Reference) -> ReferenceId {
         self.references.push(reference)
     }
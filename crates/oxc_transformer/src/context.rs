@@ -10,13 +10,18 @@ use oxc_ast::{AstBuilder, Trivias};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::SourceType;
 
-use crate::{helpers::module_imports::ModuleImports, TransformOptions};
+use crate::{
+    helpers::{module_imports::ModuleImports, runtime_helpers::RuntimeHelpers},
+    EnumMemberValues, TransformOptions,
+};
 
 pub type Ctx<'a> = Rc<TransformCtx<'a>>;
 
 pub struct TransformCtx<'a> {
     errors: RefCell<Vec<OxcDiagnostic>>,
 
+    enum_member_values: RefCell<Vec<EnumMemberValues>>,
+
     pub trivias: Trivias,
 
     pub ast: AstBuilder<'a>,
@@ -34,6 +39,9 @@ pub struct TransformCtx<'a> {
     // Helpers
     /// Manage import statement globally
     pub module_imports: ModuleImports<'a>,
+
+    /// Manage runtime helpers globally
+    pub runtime_helpers: RuntimeHelpers<'a>,
 }
 
 impl<'a> TransformCtx<'a> {
@@ -55,6 +63,7 @@ impl<'a> TransformCtx<'a> {
 
         Self {
             errors: RefCell::new(vec![]),
+            enum_member_values: RefCell::new(vec![]),
             ast: AstBuilder::new(allocator),
             filename,
             source_path,
@@ -62,6 +71,7 @@ impl<'a> TransformCtx<'a> {
             source_text,
             trivias,
             module_imports: ModuleImports::new(allocator),
+            runtime_helpers: RuntimeHelpers::new(source_type),
         }
     }
 
@@ -73,4 +83,12 @@ impl<'a> TransformCtx<'a> {
     pub fn error(&self, error: OxcDiagnostic) {
         self.errors.borrow_mut().push(error);
     }
+
+    pub fn take_enum_member_values(&self) -> Vec<EnumMemberValues> {
+        mem::take(&mut self.enum_member_values.borrow_mut())
+    }
+
+    pub fn add_enum_member_values(&self, values: EnumMemberValues) {
+        self.enum_member_values.borrow_mut().push(values);
+    }
 }
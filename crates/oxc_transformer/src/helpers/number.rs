@@ -0,0 +1,35 @@
+use oxc_ast::{ast::Expression, AstBuilder};
+use oxc_span::Span;
+use oxc_syntax::{
+    number::{NumberBase, ToJsString},
+    operator::UnaryOperator,
+};
+
+/// Create a numeric literal, with `raw` formatted the same as `Number.prototype.toString`,
+/// e.g. `2.5`, `4294967296` or `1e+21`, so it agrees with `value` when printed.
+///
+/// `value` must not be negative, negative numbers are created with [`create_number`].
+pub fn create_number_literal<'a>(ast: AstBuilder<'a>, span: Span, value: f64) -> Expression<'a> {
+    debug_assert!(!value.is_sign_negative() || value.is_nan());
+    let raw = ast.str(&value.to_js_string());
+    ast.expression_numeric_literal(span, value, raw, NumberBase::Decimal)
+}
+
+/// Create an expression evaluating to `value`.
+/// Negative numbers (including `-0`) are `-` unary expressions, and infinity is `Infinity`.
+pub fn create_number<'a>(ast: AstBuilder<'a>, span: Span, value: f64) -> Expression<'a> {
+    let is_negative = value.is_sign_negative() && !value.is_nan();
+    let value = value.abs();
+
+    let expr = if value.is_infinite() {
+        ast.expression_identifier_reference(span, "Infinity")
+    } else {
+        create_number_literal(ast, span, value)
+    };
+
+    if is_negative {
+        ast.expression_unary(span, UnaryOperator::UnaryNegation, expr)
+    } else {
+        expr
+    }
+}
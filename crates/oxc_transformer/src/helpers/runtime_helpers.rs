@@ -0,0 +1,722 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SourceType, SPAN};
+use oxc_syntax::{
+    node::NodeId,
+    operator::{
+        AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+    },
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::TraverseCtx;
+
+use super::{
+    bindings::BoundIdentifier,
+    module_imports::{ModuleImports, NamedImport},
+    number::create_number_literal,
+};
+
+/// Runtime helpers which transforms can inject, e.g. `_decorate` for legacy decorators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RuntimeHelper {
+    /// `_decorate(decorators, target, key, desc)`
+    Decorate,
+    /// `_param(paramIndex, decorator)`
+    Param,
+    /// `_metadata(metadataKey, metadataValue)`
+    Metadata,
+    /// `_createBinding(o, m, k, k2)`
+    CreateBinding,
+    /// `_exportStar(m, o)`
+    ExportStar,
+}
+
+impl RuntimeHelper {
+    /// Name of the helper's binding, before making it unique, e.g. `_decorate`.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Decorate => "decorate",
+            Self::Param => "param",
+            Self::Metadata => "metadata",
+            Self::CreateBinding => "createBinding",
+            Self::ExportStar => "exportStar",
+        }
+    }
+
+    /// Name of the helper exported by `tslib`.
+    fn tslib_name(self) -> &'static str {
+        match self {
+            Self::Decorate => "__decorate",
+            Self::Param => "__param",
+            Self::Metadata => "__metadata",
+            Self::CreateBinding => "__createBinding",
+            Self::ExportStar => "__exportStar",
+        }
+    }
+}
+
+/// Manage runtime helpers globally, so each helper is only injected once per file.
+pub struct RuntimeHelpers<'a> {
+    source_type: SourceType,
+
+    helpers: RefCell<BTreeMap<RuntimeHelper, BoundIdentifier<'a>>>,
+}
+
+impl<'a> RuntimeHelpers<'a> {
+    pub fn new(source_type: SourceType) -> Self {
+        Self { source_type, helpers: RefCell::new(BTreeMap::new()) }
+    }
+
+    /// Create a reference to `helper`, creating its binding on first use.
+    pub fn create_reference(
+        &self,
+        helper: RuntimeHelper,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut helpers = self.helpers.borrow_mut();
+        let binding = helpers.entry(helper).or_insert_with(|| {
+            BoundIdentifier::new_uid_in_root_scope(helper.name(), SymbolFlags::Function, ctx)
+        });
+        ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+    }
+
+    /// Get the statements to insert at the top of the file for the used helpers.
+    ///
+    /// With `external`, the helpers are imported from `tslib` instead of being defined in the file:
+    /// `import { __decorate as _decorate } from "tslib"` in modules, and
+    /// `const { __decorate: _decorate } = require("tslib")` in scripts.
+    pub fn get_helper_statements(
+        &self,
+        external: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<'a, Statement<'a>> {
+        let mut helpers = std::mem::take(&mut *self.helpers.borrow_mut());
+        if helpers.is_empty() {
+            return ctx.ast.vec();
+        }
+        if external && !self.source_type.is_module() {
+            return ctx.ast.vec1(Self::create_tslib_require(helpers, ctx));
+        }
+        if external {
+            let imports = ModuleImports::new(ctx.ast.allocator);
+            for (helper, binding) in helpers {
+                *ctx.symbols_mut().get_flags_mut(binding.symbol_id) = SymbolFlags::Import;
+                let import = NamedImport::new(
+                    Atom::from(helper.tslib_name()),
+                    Some(binding.name),
+                    binding.symbol_id,
+                );
+                imports.add_import(Atom::from("tslib"), import);
+            }
+            return imports.get_import_statements(ctx);
+        }
+        // `_exportStar` defines the bindings with `_createBinding`,
+        // which `tslib` doesn't need to export for it
+        if helpers.contains_key(&RuntimeHelper::ExportStar) {
+            helpers.entry(RuntimeHelper::CreateBinding).or_insert_with(|| {
+                let helper = RuntimeHelper::CreateBinding;
+                BoundIdentifier::new_uid_in_root_scope(helper.name(), SymbolFlags::Function, ctx)
+            });
+        }
+        ctx.ast.vec_from_iter(helpers.iter().map(|(helper, binding)| match helper {
+            RuntimeHelper::Decorate => Self::create_decorate_helper(binding, ctx),
+            RuntimeHelper::Param => Self::create_param_helper(binding, ctx),
+            RuntimeHelper::Metadata => Self::create_metadata_helper(binding, ctx),
+            RuntimeHelper::CreateBinding => Self::create_create_binding_helper(binding, ctx),
+            RuntimeHelper::ExportStar => {
+                let create_binding = &helpers[&RuntimeHelper::CreateBinding];
+                Self::create_export_star_helper(binding, create_binding, ctx)
+            }
+        }))
+    }
+
+    /// `const { __decorate: _decorate, __param: _param } = require("tslib");`
+    fn create_tslib_require(
+        helpers: BTreeMap<RuntimeHelper, BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let properties = ctx.ast.vec_from_iter(helpers.into_iter().map(|(helper, binding)| {
+            *ctx.symbols_mut().get_flags_mut(binding.symbol_id) =
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+            let key = ctx.ast.property_key_identifier_name(SPAN, helper.tslib_name());
+            let kind = ctx
+                .ast
+                .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier());
+            let value = ctx.ast.binding_pattern(kind, NONE, false);
+            ctx.ast.binding_property(SPAN, key, value, false, false)
+        }));
+        let id = ctx.ast.binding_pattern(
+            ctx.ast.binding_pattern_kind_object_pattern(SPAN, properties, NONE),
+            NONE,
+            false,
+        );
+        let callee = Self::create_global_reference("require", ctx);
+        let arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, "tslib")));
+        let init = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let kind = VariableDeclarationKind::Const;
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        Statement::from(ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false))
+    }
+
+    /// ```js
+    /// function _decorate(decorators, target, key, desc) {
+    ///   var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    ///   if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    ///   else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    ///   return c > 3 && r && Object.defineProperty(target, key, r), r;
+    /// }
+    /// ```
+    fn create_decorate_helper(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scopes().root_scope_id(), ScopeFlags::Function);
+        let decorators = Self::create_helper_binding("decorators", scope_id, ctx);
+        let target = Self::create_helper_binding("target", scope_id, ctx);
+        let key = Self::create_helper_binding("key", scope_id, ctx);
+        let desc = Self::create_helper_binding("desc", scope_id, ctx);
+        let c = Self::create_helper_binding("c", scope_id, ctx);
+        let r = Self::create_helper_binding("r", scope_id, ctx);
+        let d = Self::create_helper_binding("d", scope_id, ctx);
+        let i = Self::create_helper_binding("i", scope_id, ctx);
+
+        let read = |binding: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>| {
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+        };
+        // Assignments whose value is used also read the binding
+        let assign = |binding: &BoundIdentifier<'a>,
+                      value: Expression<'a>,
+                      flags: ReferenceFlags,
+                      ctx: &mut TraverseCtx<'a>| {
+            let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+                binding.create_spanned_reference(SPAN, flags, ctx),
+            );
+            ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                value,
+            )
+        };
+        let compare = |binding: &BoundIdentifier<'a>,
+                       operator: BinaryOperator,
+                       value: f64,
+                       ctx: &mut TraverseCtx<'a>| {
+            let left = read(binding, ctx);
+            let right = create_number_literal(ctx.ast, SPAN, value);
+            ctx.ast.expression_binary(SPAN, left, operator, right)
+        };
+        let call = |callee: Expression<'a>,
+                    arguments: &[&BoundIdentifier<'a>],
+                    ctx: &mut TraverseCtx<'a>| {
+            let arguments = ctx.ast.vec_from_iter(
+                arguments
+                    .iter()
+                    .map(|binding| Argument::from(read(binding, ctx)))
+                    .collect::<std::vec::Vec<_>>(),
+            );
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        };
+        let member = |object: Expression<'a>, property: &'static str, ctx: &mut TraverseCtx<'a>| {
+            let property = ctx.ast.identifier_name(SPAN, property);
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+        };
+        // `typeof Reflect === type`
+        let is_type_of = |expr: Expression<'a>, r#type: &'static str, ctx: &mut TraverseCtx<'a>| {
+            let left = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, expr);
+            let right = ctx.ast.expression_string_literal(SPAN, r#type);
+            ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+        };
+
+        let mut stmts = ctx.ast.vec_with_capacity(3);
+
+        // var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+        {
+            let arguments = ctx.create_unbound_reference_id(
+                SPAN,
+                Atom::from("arguments"),
+                ReferenceFlags::Read,
+            );
+            let arguments_length =
+                member(ctx.ast.expression_from_identifier_reference(arguments), "length", ctx);
+            let descriptor = {
+                let object = Self::create_global_reference("Object", ctx);
+                let callee = member(object, "getOwnPropertyDescriptor", ctx);
+                let get_descriptor = call(callee, &[&target, &key], ctx);
+                let test = {
+                    let left = read(&desc, ctx);
+                    let right = ctx.ast.expression_null_literal(SPAN);
+                    ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+                };
+                let consequent = assign(
+                    &desc,
+                    get_descriptor,
+                    ReferenceFlags::Read | ReferenceFlags::Write,
+                    ctx,
+                );
+                let alternate = read(&desc, ctx);
+                ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+            };
+            let r_init = {
+                let test = compare(&c, BinaryOperator::LessThan, 3.0, ctx);
+                let consequent = read(&target, ctx);
+                ctx.ast.expression_conditional(SPAN, test, consequent, descriptor)
+            };
+            let declarators = ctx.ast.vec_from_iter([
+                Self::create_declarator(&c, Some(arguments_length), ctx),
+                Self::create_declarator(&r, Some(r_init), ctx),
+                Self::create_declarator(&d, None, ctx),
+            ]);
+            let kind = VariableDeclarationKind::Var;
+            stmts.push(Statement::from(ctx.ast.declaration_variable(
+                SPAN,
+                kind,
+                declarators,
+                false,
+            )));
+        }
+
+        // if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+        // else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+        {
+            let test = {
+                let reflect = Self::create_global_reference("Reflect", ctx);
+                let left = is_type_of(reflect, "object", ctx);
+                let reflect = Self::create_global_reference("Reflect", ctx);
+                let reflect_decorate = member(reflect, "decorate", ctx);
+                let right = is_type_of(reflect_decorate, "function", ctx);
+                ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right)
+            };
+            let consequent = {
+                let reflect = Self::create_global_reference("Reflect", ctx);
+                let callee = member(reflect, "decorate", ctx);
+                let value = call(callee, &[&decorators, &target, &key, &desc], ctx);
+                ctx.ast.statement_expression(SPAN, assign(&r, value, ReferenceFlags::Write, ctx))
+            };
+            let alternate = {
+                let init = {
+                    let length = member(read(&decorators, ctx), "length", ctx);
+                    let one = create_number_literal(ctx.ast, SPAN, 1.0);
+                    let value =
+                        ctx.ast.expression_binary(SPAN, length, BinaryOperator::Subtraction, one);
+                    let kind = VariableDeclarationKind::Var;
+                    let declarators = ctx.ast.vec1(Self::create_declarator(&i, Some(value), ctx));
+                    ctx.ast.for_statement_init_variable_declaration(SPAN, kind, declarators, false)
+                };
+                let test = compare(&i, BinaryOperator::GreaterEqualThan, 0.0, ctx);
+                let update = {
+                    let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+                        i.create_read_write_reference(ctx),
+                    );
+                    ctx.ast.expression_update(SPAN, UpdateOperator::Decrement, false, target)
+                };
+                let body = {
+                    let element = Expression::from(ctx.ast.member_expression_computed(
+                        SPAN,
+                        read(&decorators, ctx),
+                        read(&i, ctx),
+                        false,
+                    ));
+                    let test =
+                        assign(&d, element, ReferenceFlags::Read | ReferenceFlags::Write, ctx);
+                    let decorated = {
+                        let call_d =
+                            |arguments: &[&BoundIdentifier<'a>], ctx: &mut TraverseCtx<'a>| {
+                                let callee = read(&d, ctx);
+                                call(callee, arguments, ctx)
+                            };
+                        let test = compare(&c, BinaryOperator::LessThan, 3.0, ctx);
+                        let consequent = call_d(&[&r], ctx);
+                        let alternate = {
+                            let test = compare(&c, BinaryOperator::GreaterThan, 3.0, ctx);
+                            let consequent = call_d(&[&target, &key, &r], ctx);
+                            let alternate = call_d(&[&target, &key], ctx);
+                            ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+                        };
+                        let conditional =
+                            ctx.ast.expression_conditional(SPAN, test, consequent, alternate);
+                        let left = ctx.ast.expression_parenthesized(SPAN, conditional);
+                        let right = read(&r, ctx);
+                        ctx.ast.expression_logical(SPAN, left, LogicalOperator::Or, right)
+                    };
+                    let consequent = ctx.ast.statement_expression(
+                        SPAN,
+                        assign(&r, decorated, ReferenceFlags::Write, ctx),
+                    );
+                    ctx.ast.statement_if(SPAN, test, consequent, None)
+                };
+                let for_statement =
+                    ctx.ast.alloc_for_statement(SPAN, Some(init), Some(test), Some(update), body);
+                for_statement
+                    .scope_id
+                    .set(Some(ctx.create_child_scope(scope_id, ScopeFlags::empty())));
+                Statement::ForStatement(for_statement)
+            };
+            stmts.push(ctx.ast.statement_if(SPAN, test, consequent, Some(alternate)));
+        }
+
+        // return c > 3 && r && Object.defineProperty(target, key, r), r;
+        {
+            let define = {
+                let object = Self::create_global_reference("Object", ctx);
+                let callee = member(object, "defineProperty", ctx);
+                call(callee, &[&target, &key, &r], ctx)
+            };
+            let left = compare(&c, BinaryOperator::GreaterThan, 3.0, ctx);
+            let right = read(&r, ctx);
+            let left = ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right);
+            let condition = ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, define);
+            let result = read(&r, ctx);
+            let sequence =
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([condition, result]));
+            stmts.push(ctx.ast.statement_return(SPAN, Some(sequence)));
+        }
+
+        let params = [&decorators, &target, &key, &desc];
+        Self::create_helper_function(binding, &params, stmts, scope_id, ctx)
+    }
+
+    /// ```js
+    /// function _param(paramIndex, decorator) {
+    ///   return function (target, key) { decorator(target, key, paramIndex); };
+    /// }
+    /// ```
+    fn create_param_helper(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scopes().root_scope_id(), ScopeFlags::Function);
+        let param_index = Self::create_helper_binding("paramIndex", scope_id, ctx);
+        let decorator = Self::create_helper_binding("decorator", scope_id, ctx);
+
+        let inner_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+        let target = Self::create_helper_binding("target", inner_scope_id, ctx);
+        let key = Self::create_helper_binding("key", inner_scope_id, ctx);
+
+        let inner_function = {
+            let callee =
+                ctx.ast.expression_from_identifier_reference(decorator.create_read_reference(ctx));
+            let arguments = ctx.ast.vec_from_iter(
+                [&target, &key, &param_index]
+                    .into_iter()
+                    .map(|binding| {
+                        Argument::from(ctx.ast.expression_from_identifier_reference(
+                            binding.create_read_reference(ctx),
+                        ))
+                    })
+                    .collect::<std::vec::Vec<_>>(),
+            );
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            let body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+            let function = Self::create_function(
+                FunctionType::FunctionExpression,
+                None,
+                &[&target, &key],
+                body,
+                inner_scope_id,
+                ctx,
+            );
+            ctx.ast.expression_from_function(function)
+        };
+
+        let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(inner_function)));
+        Self::create_helper_function(binding, &[&param_index, &decorator], stmts, scope_id, ctx)
+    }
+
+    /// ```js
+    /// function _metadata(metadataKey, metadataValue) {
+    ///   if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(metadataKey, metadataValue);
+    /// }
+    /// ```
+    fn create_metadata_helper(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scopes().root_scope_id(), ScopeFlags::Function);
+        let metadata_key = Self::create_helper_binding("metadataKey", scope_id, ctx);
+        let metadata_value = Self::create_helper_binding("metadataValue", scope_id, ctx);
+
+        let reflect_metadata = |ctx: &mut TraverseCtx<'a>| {
+            let object = Self::create_global_reference("Reflect", ctx);
+            let property = ctx.ast.identifier_name(SPAN, "metadata");
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+        };
+        // `typeof Reflect === type`
+        let is_type_of = |expr: Expression<'a>, r#type: &'static str, ctx: &mut TraverseCtx<'a>| {
+            let left = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, expr);
+            let right = ctx.ast.expression_string_literal(SPAN, r#type);
+            ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+        };
+
+        let test = {
+            let reflect = Self::create_global_reference("Reflect", ctx);
+            let left = is_type_of(reflect, "object", ctx);
+            let right = is_type_of(reflect_metadata(ctx), "function", ctx);
+            ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right)
+        };
+        let consequent = {
+            let callee = reflect_metadata(ctx);
+            let arguments = ctx.ast.vec_from_iter(
+                [&metadata_key, &metadata_value]
+                    .into_iter()
+                    .map(|binding| {
+                        Argument::from(ctx.ast.expression_from_identifier_reference(
+                            binding.create_read_reference(ctx),
+                        ))
+                    })
+                    .collect::<std::vec::Vec<_>>(),
+            );
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            ctx.ast.statement_return(SPAN, Some(call))
+        };
+
+        let stmts = ctx.ast.vec1(ctx.ast.statement_if(SPAN, test, consequent, None));
+        Self::create_helper_function(
+            binding,
+            &[&metadata_key, &metadata_value],
+            stmts,
+            scope_id,
+            ctx,
+        )
+    }
+
+    /// ```js
+    /// function _createBinding(o, m, k, k2) {
+    ///   Object.defineProperty(o, k2 === void 0 ? k : k2, { enumerable: true, get: function() { return m[k]; } });
+    /// }
+    /// ```
+    fn create_create_binding_helper(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scopes().root_scope_id(), ScopeFlags::Function);
+        let o = Self::create_helper_binding("o", scope_id, ctx);
+        let m = Self::create_helper_binding("m", scope_id, ctx);
+        let k = Self::create_helper_binding("k", scope_id, ctx);
+        let k2 = Self::create_helper_binding("k2", scope_id, ctx);
+
+        let read = |binding: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>| {
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+        };
+
+        // `k2 === void 0 ? k : k2`
+        let key = {
+            let left = read(&k2, ctx);
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                left,
+                BinaryOperator::StrictEquality,
+                ctx.ast.void_0(),
+            );
+            let consequent = read(&k, ctx);
+            let alternate = read(&k2, ctx);
+            ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+        };
+        // `{ enumerable: true, get: function() { return m[k]; } }`
+        let descriptor = {
+            let create_property = |name: &'static str, value, ctx: &mut TraverseCtx<'a>| {
+                let key = ctx.ast.property_key_identifier_name(SPAN, name);
+                ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    None,
+                    false,
+                    false,
+                    false,
+                )
+            };
+            let getter = {
+                let getter_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+                let object = read(&m, ctx);
+                let property = read(&k, ctx);
+                let member = ctx.ast.member_expression_computed(SPAN, object, property, false);
+                let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(member.into())));
+                let function = Self::create_function(
+                    FunctionType::FunctionExpression,
+                    None,
+                    &[],
+                    body,
+                    getter_scope_id,
+                    ctx,
+                );
+                ctx.ast.expression_from_function(function)
+            };
+            let properties = ctx.ast.vec_from_iter([
+                create_property("enumerable", ctx.ast.expression_boolean_literal(SPAN, true), ctx),
+                create_property("get", getter, ctx),
+            ]);
+            ctx.ast.expression_object(SPAN, properties, None)
+        };
+        let define_property = {
+            let object = Self::create_global_reference("Object", ctx);
+            let property = ctx.ast.identifier_name(SPAN, "defineProperty");
+            let callee =
+                Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false));
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(read(&o, ctx)),
+                Argument::from(key),
+                Argument::from(descriptor),
+            ]);
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        };
+
+        let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, define_property));
+        Self::create_helper_function(binding, &[&o, &m, &k, &k2], stmts, scope_id, ctx)
+    }
+
+    /// ```js
+    /// function _exportStar(m, o) {
+    ///   for (var p in m) if (p !== "default" && !Object.prototype.hasOwnProperty.call(o, p)) _createBinding(o, m, p);
+    /// }
+    /// ```
+    fn create_export_star_helper(
+        binding: &BoundIdentifier<'a>,
+        create_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scopes().root_scope_id(), ScopeFlags::Function);
+        let m = Self::create_helper_binding("m", scope_id, ctx);
+        let o = Self::create_helper_binding("o", scope_id, ctx);
+        let p = Self::create_helper_binding("p", scope_id, ctx);
+
+        let read = |binding: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>| {
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+        };
+        let member = |object: Expression<'a>, property: &'static str, ctx: &mut TraverseCtx<'a>| {
+            let property = ctx.ast.identifier_name(SPAN, property);
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+        };
+
+        // `p !== "default" && !Object.prototype.hasOwnProperty.call(o, p)`
+        let test = {
+            let left = {
+                let left = read(&p, ctx);
+                let right = ctx.ast.expression_string_literal(SPAN, "default");
+                ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictInequality, right)
+            };
+            let right = {
+                let object = Self::create_global_reference("Object", ctx);
+                let prototype = member(object, "prototype", ctx);
+                let has_own_property = member(prototype, "hasOwnProperty", ctx);
+                let callee = member(has_own_property, "call", ctx);
+                let arguments = ctx
+                    .ast
+                    .vec_from_iter([Argument::from(read(&o, ctx)), Argument::from(read(&p, ctx))]);
+                let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, call)
+            };
+            ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right)
+        };
+        // `_createBinding(o, m, p)`
+        let consequent = {
+            let callee = read(create_binding, ctx);
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(read(&o, ctx)),
+                Argument::from(read(&m, ctx)),
+                Argument::from(read(&p, ctx)),
+            ]);
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            ctx.ast.statement_expression(SPAN, call)
+        };
+        let for_in_statement = {
+            let kind = VariableDeclarationKind::Var;
+            let declarators = ctx.ast.vec1(Self::create_declarator(&p, None, ctx));
+            let left =
+                ctx.ast.for_statement_left_variable_declaration(SPAN, kind, declarators, false);
+            let right = read(&m, ctx);
+            let body = ctx.ast.statement_if(SPAN, test, consequent, None);
+            let for_in_statement = ctx.ast.alloc_for_in_statement(SPAN, left, right, body);
+            for_in_statement
+                .scope_id
+                .set(Some(ctx.create_child_scope(scope_id, ScopeFlags::empty())));
+            Statement::ForInStatement(for_in_statement)
+        };
+
+        let stmts = ctx.ast.vec1(for_in_statement);
+        Self::create_helper_function(binding, &[&m, &o], stmts, scope_id, ctx)
+    }
+
+    fn create_helper_function(
+        binding: &BoundIdentifier<'a>,
+        params: &[&BoundIdentifier<'a>],
+        stmts: Vec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let function = Self::create_function(
+            FunctionType::FunctionDeclaration,
+            Some(binding.create_binding_identifier()),
+            params,
+            stmts,
+            scope_id,
+            ctx,
+        );
+        Statement::from(ctx.ast.declaration_from_function(function))
+    }
+
+    fn create_function(
+        r#type: FunctionType,
+        id: Option<BindingIdentifier<'a>>,
+        params: &[&BoundIdentifier<'a>],
+        stmts: Vec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let items = ctx.ast.vec_from_iter(params.iter().map(|binding| {
+            let kind = ctx
+                .ast
+                .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier());
+            ctx.ast.plain_formal_parameter(SPAN, ctx.ast.binding_pattern(kind, NONE, false))
+        }));
+        let params =
+            ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE);
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), stmts);
+        let function = ctx.ast.plain_function(r#type, SPAN, id, params, Some(body));
+        function.scope_id.set(Some(scope_id));
+        function
+    }
+
+    /// Create a binding with the exact `name` for a parameter or variable of a helper.
+    fn create_helper_binding(
+        name: &'static str,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let name = Atom::from(name);
+        let symbol_id = ctx.symbols_mut().create_symbol(
+            SPAN,
+            name.to_compact_str(),
+            SymbolFlags::FunctionScopedVariable,
+            scope_id,
+            NodeId::DUMMY,
+        );
+        ctx.scopes_mut().add_binding(scope_id, name.to_compact_str(), symbol_id);
+        BoundIdentifier { name, symbol_id }
+    }
+
+    fn create_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        let kind = ctx
+            .ast
+            .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier());
+        let id = ctx.ast.binding_pattern(kind, NONE, false);
+        ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, init, false)
+    }
+
+    /// `Object`, `Reflect` or `require`. Helpers refer to the globals, so the reference is unbound
+    /// even if the file declares a binding with the same name, the same as TypeScript's helpers.
+    fn create_global_reference(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let ident = ctx.create_unbound_reference_id(SPAN, Atom::from(name), ReferenceFlags::Read);
+        ctx.ast.expression_from_identifier_reference(ident)
+    }
+}
@@ -27,6 +27,8 @@ mod typescript;
 mod helpers {
     pub mod bindings;
     pub mod module_imports;
+    pub mod number;
+    pub mod runtime_helpers;
 }
 
 use std::{path::Path, rc::Rc};
@@ -50,7 +52,10 @@ pub use crate::{
     es2015::{ArrowFunctionsOptions, ES2015Options},
     options::{BabelOptions, TransformOptions},
     react::{ReactJsxRuntime, ReactOptions, ReactRefreshOptions},
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    typescript::{
+        EnumMemberValue, EnumMemberValues, EnumOutputStyle, ImportsNotUsedAsValues,
+        RewriteExtensionsMode, TypeScriptOptions,
+    },
 };
 use crate::{
     context::{Ctx, TransformCtx},
@@ -63,6 +68,8 @@ pub struct TransformerReturn {
     pub errors: std::vec::Vec<OxcDiagnostic>,
     pub symbols: SymbolTable,
     pub scopes: ScopeTree,
+    /// The members of every enum transformed by the TypeScript transform, in source order.
+    pub enums: std::vec::Vec<EnumMemberValues>,
 }
 
 pub struct Transformer<'a> {
@@ -118,7 +125,12 @@ impl<'a> Transformer<'a> {
     ) -> TransformerReturn {
         let allocator = self.ctx.ast.allocator;
         let (symbols, scopes) = traverse_mut(&mut self, allocator, program, symbols, scopes);
-        TransformerReturn { errors: self.ctx.take_errors(), symbols, scopes }
+        TransformerReturn {
+            errors: self.ctx.take_errors(),
+            symbols,
+            scopes,
+            enums: self.ctx.take_enum_member_values(),
+        }
     }
 }
 
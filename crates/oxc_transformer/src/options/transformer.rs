@@ -89,6 +89,12 @@ impl TransformOptions {
         }
     }
 
+    /// Only transform TypeScript, e.g.
+    /// `TransformOptions::typescript(TypeScriptOptions::default().with_isolated_modules(true))`.
+    pub fn typescript(typescript: TypeScriptOptions) -> Self {
+        Self { typescript, ..Self::default() }
+    }
+
     fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
             es2015: ES2015Options::from_targets_and_bugfixes(targets, bugfixes),
@@ -343,3 +349,30 @@ fn test_deny_unknown_fields() {
         result.err().unwrap().iter().map(ToString::to_string).collect::<Vec<_>>().join("\n");
     assert!(err_message.contains("transform-react-jsx: unknown field `filter`"));
 }
+
+#[test]
+fn test_typescript_builder() {
+    use crate::{EnumOutputStyle, ImportsNotUsedAsValues};
+
+    let options = TransformOptions::typescript(
+        TypeScriptOptions::default()
+            .with_only_remove_type_imports(true)
+            .with_use_define_for_class_fields(false)
+            .with_experimental_decorators(true)
+            .with_emit_decorator_metadata(true)
+            .with_imports_not_used_as_values(ImportsNotUsedAsValues::Preserve)
+            .with_enum_output_style(EnumOutputStyle::Classic)
+            .with_require_name("__require"),
+    );
+    let typescript = &options.typescript;
+    assert!(typescript.only_remove_type_imports);
+    assert!(!typescript.use_define_for_class_fields);
+    assert!(typescript.experimental_decorators);
+    assert!(typescript.emit_decorator_metadata);
+    assert_eq!(typescript.imports_not_used_as_values, ImportsNotUsedAsValues::Preserve);
+    assert_eq!(typescript.enum_output_style, EnumOutputStyle::Classic);
+    assert_eq!(typescript.require_name, "__require");
+    // Options which weren't set keep their defaults
+    assert!(typescript.allow_declare_fields);
+    assert!(!typescript.optimize_const_enums);
+}
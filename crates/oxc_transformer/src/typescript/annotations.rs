@@ -3,9 +3,9 @@
 use std::{cell::Cell, rc::Rc};
 
 use oxc_allocator::Vec as ArenaVec;
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, NONE};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::SymbolFlags;
+use oxc_semantic::{Reference, SymbolFlags};
 use oxc_span::{Atom, GetSpan, Span, SPAN};
 use oxc_syntax::{
     operator::AssignmentOperator,
@@ -16,10 +16,12 @@ use oxc_syntax::{
 use oxc_traverse::{Traverse, TraverseCtx};
 use rustc_hash::FxHashSet;
 
-use crate::{context::Ctx, TypeScriptOptions};
+use super::diagnostics;
+use crate::{
+    context::Ctx, helpers::bindings::BoundIdentifier, ImportsNotUsedAsValues, TypeScriptOptions,
+};
 
 pub struct TypeScriptAnnotations<'a> {
-    #[allow(dead_code)]
     options: Rc<TypeScriptOptions>,
     ctx: Ctx<'a>,
     /// Assignments to be added to the constructor body
@@ -65,16 +67,31 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         let mut no_modules_remaining = true;
         let mut some_modules_deleted = false;
 
+        // TODO: Keep the leading comments of removed statements, e.g. license banners.
+        // Codegen only prints annotation comments, which it looks up by the positions of
+        // the printed nodes, so there's nowhere to move them to yet.
         program.body.retain_mut(|stmt| {
             let need_retain = match stmt {
                 Statement::ExportNamedDeclaration(decl) => {
                     if decl.export_kind.is_type() {
                         false
+                    } else if decl.source.is_some() {
+                        // `export { type A, b } from 'mod'`, the specifiers don't refer to local
+                        // bindings, so only the ones marked as types are removed
+                        decl.specifiers.retain(|specifier| !specifier.export_kind.is_type());
+                        if self.options.report_ambiguous_re_exports
+                            && !self.options.only_remove_type_imports
+                        {
+                            self.report_ambiguous_re_exports(&decl.specifiers, ctx);
+                        }
+                        !decl.specifiers.is_empty()
                     } else {
                         decl.specifiers.retain(|specifier| {
-                            !(specifier.export_kind.is_type()
-                                || self.type_identifier_names.contains(&specifier.exported.name())
-                                || {
+                            if specifier.export_kind.is_type() {
+                                return false;
+                            }
+                            let is_type =
+                                self.type_identifier_names.contains(&specifier.local.name()) || {
                                     if let ModuleExportName::IdentifierReference(ident) =
                                         &specifier.local
                                     {
@@ -84,7 +101,14 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
                                     } else {
                                         false
                                     }
-                                })
+                                };
+                            // Other modules can't tell that this is a type without `export type`
+                            if is_type && self.options.isolated_modules {
+                                self.ctx.error(diagnostics::type_re_export_requires_export_type(
+                                    specifier.span,
+                                ));
+                            }
+                            !is_type
                         });
 
                         !decl.specifiers.is_empty()
@@ -95,11 +119,38 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
                     }
                 }
                 Statement::ExportAllDeclaration(decl) => !decl.export_kind.is_type(),
-                Statement::ExportDefaultDeclaration(decl) => !decl.is_typescript_syntax(),
+                Statement::ExportDefaultDeclaration(decl) => {
+                    !decl.is_typescript_syntax() && !self.is_type_default_export(decl, ctx)
+                }
                 Statement::ImportDeclaration(decl) => {
                     if decl.import_kind.is_type() {
+                        // `import type { type A } from 'mod'`, the whole declaration is removed anyway
+                        for specifier in decl.specifiers.iter().flatten() {
+                            if let ImportDeclarationSpecifier::ImportSpecifier(s) = specifier {
+                                if s.import_kind.is_type() {
+                                    self.ctx
+                                        .error(diagnostics::type_modifier_on_type_import(s.span));
+                                }
+                            }
+                        }
                         false
                     } else if self.options.only_remove_type_imports {
+                        // Only `type` specifiers are removed, value imports are kept even if unused.
+                        // `import { type A } from 'mod'` -> `import 'mod'`
+                        if let Some(specifiers) = &mut decl.specifiers {
+                            specifiers.retain(|specifier| match specifier {
+                                ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                                    !s.import_kind.is_type()
+                                }
+                                _ => true,
+                            });
+                            if self.options.report_type_only_imports {
+                                self.report_type_only_imports(specifiers, ctx);
+                            }
+                            if specifiers.is_empty() {
+                                decl.specifiers = None;
+                            }
+                        }
                         true
                     } else if let Some(specifiers) = &mut decl.specifiers {
                         if specifiers.is_empty() {
@@ -107,6 +158,7 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
                             decl.specifiers = None;
                             true
                         } else {
+                            let mut has_unused_value_import = false;
                             specifiers.retain(|specifier| {
                                 let id = match specifier {
                                     ImportDeclarationSpecifier::ImportSpecifier(s) => {
@@ -122,17 +174,37 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
                                         &s.local
                                     }
                                 };
-                                self.has_value_reference(&id.name, ctx)
+                                let has_value_reference = self.has_value_reference(&id.name, ctx);
+                                has_unused_value_import |= !has_value_reference;
+                                has_value_reference
                             });
-                            !specifiers.is_empty()
+                            if !specifiers.is_empty() {
+                                true
+                            } else if self.options.imports_not_used_as_values
+                                == ImportsNotUsedAsValues::Remove
+                            {
+                                false
+                            } else {
+                                if has_unused_value_import
+                                    && self.options.imports_not_used_as_values
+                                        == ImportsNotUsedAsValues::Error
+                                {
+                                    self.ctx
+                                        .error(diagnostics::import_not_used_as_value(decl.span));
+                                }
+                                // Kept for its side effects, `import { A } from 'mod'` -> `import 'mod'`
+                                decl.specifiers = None;
+                                true
+                            }
                         }
                     } else {
+                        // `import 'mod'` is always kept for its side effects
                         true
                     }
                 }
-                Statement::TSExportAssignment(_) | Statement::TSNamespaceExportDeclaration(_) => {
-                    false
-                }
+                Statement::TSExportAssignment(_) => false,
+                // `export as namespace Foo` declares a UMD global, it doesn't make the file a module
+                Statement::TSNamespaceExportDeclaration(_) => return false,
                 _ => return true,
             };
 
@@ -148,7 +220,7 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         // Determine if we still have import/export statements, otherwise we
         // need to inject an empty statement (`export {}`) so that the file is
         // still considered a module
-        if no_modules_remaining && some_modules_deleted {
+        if no_modules_remaining && some_modules_deleted && self.options.add_esm_marker {
             let export_decl = ModuleDeclaration::ExportNamedDeclaration(
                 self.ctx.ast.plain_export_named_declaration(SPAN, self.ctx.ast.vec(), None),
             );
@@ -177,11 +249,40 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         expr.type_parameters = None;
     }
 
-    fn enter_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         class.type_parameters = None;
         class.super_type_parameters = None;
         class.implements = None;
         class.r#abstract = false;
+
+        // `declare` fields are removed in `enter_class_body`. Other type-only fields are kept,
+        // as they define a property at runtime, unless `allow_declare_fields` is disabled.
+        if !self.options.allow_declare_fields {
+            // `static x: number` -> `static x = void 0`
+            for element in class.body.body.iter_mut() {
+                if !Self::is_type_only_field(element) {
+                    continue;
+                }
+                if let ClassElement::PropertyDefinition(def) = element {
+                    if def.r#static {
+                        def.value = Some(ctx.ast.void_0());
+                    }
+                }
+            }
+        }
+
+        if !self.options.use_define_for_class_fields {
+            self.move_fields_to_constructor(class, Self::is_constructor_initialized_field, ctx);
+        } else if !self.options.allow_declare_fields {
+            self.move_fields_to_constructor(
+                class,
+                |element| {
+                    Self::is_constructor_initialized_field(element)
+                        && Self::is_type_only_field(element)
+                },
+                ctx,
+            );
+        }
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {
@@ -319,6 +420,7 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
             // we simply add assignments to the bottom of the function body
             if self.has_super_call {
                 self.assignments.clear();
+                self.has_super_call = false;
             } else {
                 def.value
                     .body
@@ -393,6 +495,8 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
                 }
             },
         );
+
+        self.move_overload_comments_to_implementation(stmts);
     }
 
     fn exit_statements(
@@ -400,8 +504,16 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         stmts: &mut ArenaVec<'a, Statement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        let is_top_level = ctx.scopes().get_flags(ctx.current_scope_id()).is_top();
         // Remove TS specific statements
         stmts.retain(|stmt| match stmt {
+            // `import A = Type.A; export { A }`, the export is removed as well
+            Statement::TSImportEqualsDeclaration(decl) => {
+                if is_top_level {
+                    self.type_identifier_names.insert(decl.id.name.clone());
+                }
+                false
+            }
             Statement::ExpressionStatement(s) => !s.expression.is_typescript_syntax(),
             // Any namespaces left after namespace transform are type only, so remove them
             Statement::TSModuleDeclaration(_) => false,
@@ -541,6 +653,69 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
 }
 
 impl<'a> TypeScriptAnnotations<'a> {
+    /// Check if the default export refers to a type, e.g. a type-only import or an interface,
+    /// which doesn't exist at runtime
+    /// `import type A from 'mod'; export default A;`
+    /// `interface B {} export default (B);`
+    fn is_type_default_export(
+        &self,
+        decl: &ExportDefaultDeclaration<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let Some(expr) = decl.declaration.as_expression() else {
+            return false;
+        };
+        let Expression::Identifier(ident) = expr.get_inner_expression() else {
+            return false;
+        };
+        if self.type_identifier_names.contains(&ident.name) {
+            return true;
+        }
+        // Value references aren't resolved to types, so the binding is looked up by name
+        ctx.scopes().get_root_binding(&ident.name).is_some_and(|symbol_id| {
+            let flags = ctx.symbols().get_flags(symbol_id);
+            flags.is_type() && !flags.is_value()
+        })
+    }
+
+    /// Report specifiers of `export { A } from 'mod'` which can't be resolved to a local value,
+    /// as whether they are types is only known by checking `mod`.
+    fn report_ambiguous_re_exports(
+        &self,
+        specifiers: &[ExportSpecifier<'a>],
+        ctx: &TraverseCtx<'a>,
+    ) {
+        for specifier in specifiers {
+            let name = specifier.local.name();
+            let is_local_value = ctx
+                .scopes()
+                .get_root_binding(&name)
+                .is_some_and(|symbol_id| ctx.symbols().get_flags(symbol_id).is_value());
+            if !is_local_value {
+                self.ctx.error(diagnostics::ambiguous_re_export(&name, specifier.span));
+            }
+        }
+    }
+
+    /// Report value imports kept by [`TypeScriptOptions::only_remove_type_imports`] which are
+    /// only referenced as types, as they may not exist at runtime.
+    fn report_type_only_imports(
+        &self,
+        specifiers: &[ImportDeclarationSpecifier<'a>],
+        ctx: &TraverseCtx<'a>,
+    ) {
+        for specifier in specifiers {
+            let (span, local) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => (s.span, &s.local),
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => (s.span, &s.local),
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => (s.span, &s.local),
+            };
+            if self.has_only_type_references(&local.name, ctx) {
+                self.ctx.error(diagnostics::import_only_used_as_type(&local.name, span));
+            }
+        }
+    }
+
     /// Check if the given name is a JSX pragma or fragment pragma import
     /// and if the file contains JSX elements or fragments
     fn is_jsx_imports(&self, name: &str) -> bool {
@@ -548,6 +723,281 @@ impl<'a> TypeScriptAnnotations<'a> {
             || self.has_jsx_fragment && name == self.jsx_fragment_import_name
     }
 
+    /// Overload signatures are removed in [`Self::exit_statements`] and [`Self::exit_program`],
+    /// which would drop the annotation comments (e.g. `/* #__NO_SIDE_EFFECTS__ */`) written
+    /// before the first signature. Extend the implementation's span to cover its signatures so
+    /// that those comments are printed before the implementation instead.
+    ///
+    /// ```ts
+    /// /* #__NO_SIDE_EFFECTS__ */ export function foo(a: string): string;
+    /// export function foo(a: any) { return a }
+    /// ```
+    fn move_overload_comments_to_implementation(&self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let mut overloads: Option<(Atom<'a>, u32)> = None;
+        for stmt in stmts.iter_mut() {
+            let Some((start, func)) = Self::function_declaration(stmt) else {
+                overloads = None;
+                continue;
+            };
+            let name = func.id.as_ref().map(|id| id.name.clone());
+            let is_signature = func.body.is_none() && !func.declare;
+            match (&overloads, name) {
+                (Some((overload_name, overload_start)), Some(name)) if *overload_name == name => {
+                    if !is_signature {
+                        Self::set_function_declaration_start(stmt, *overload_start);
+                        overloads = None;
+                    }
+                }
+                (_, Some(name)) if is_signature => overloads = Some((name, start)),
+                _ => overloads = None,
+            }
+        }
+    }
+
+    /// Returns the start of the span the leading comments of a (possibly exported) function
+    /// declaration are attached to, and the function itself.
+    fn function_declaration<'b>(stmt: &'b Statement<'a>) -> Option<(u32, &'b Function<'a>)> {
+        match stmt {
+            Statement::FunctionDeclaration(func) => Some((func.span.start, func)),
+            Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::FunctionDeclaration(func)) => Some((decl.span.start, func)),
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    Some((func.span.start, func))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn set_function_declaration_start(stmt: &mut Statement<'a>, start: u32) {
+        match stmt {
+            Statement::FunctionDeclaration(func) => func.span.start = start,
+            Statement::ExportNamedDeclaration(decl) => decl.span.start = start,
+            Statement::ExportDefaultDeclaration(decl) => {
+                if let ExportDefaultDeclarationKind::FunctionDeclaration(func) =
+                    &mut decl.declaration
+                {
+                    func.span.start = start;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Initialize the instance fields matched by `predicate` in the constructor, for
+    /// `use_define_for_class_fields` and `allow_declare_fields` being disabled.
+    ///
+    /// ```ts
+    /// class C extends B { x = 1; y: number; }
+    /// ```
+    /// ```js
+    /// class C extends B { constructor(..._args) { super(..._args); this.x = 1; this.y = void 0; } }
+    /// ```
+    fn move_fields_to_constructor(
+        &self,
+        class: &mut Class<'a>,
+        predicate: fn(&ClassElement<'a>) -> bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !class.body.body.iter().any(predicate) {
+            return;
+        }
+
+        let is_derived = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(def) if def.kind.is_constructor() => Some(&mut **def),
+            _ => None,
+        });
+        // The assignments go after `super()`, which can't be found if it is nested,
+        // e.g. `if (a) super(); else super();`
+        let insert_index = match constructor.as_ref().and_then(|def| def.value.body.as_ref()) {
+            Some(body) if is_derived => {
+                let Some(index) = body.statements.iter().position(|stmt| {
+                    matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression())
+                }) else {
+                    return;
+                };
+                index + 1
+            }
+            _ => 0,
+        };
+
+        let mut assignments = ctx.ast.vec();
+        let mut elements = ctx.ast.vec_with_capacity(class.body.body.len());
+        for element in ctx.ast.move_vec(&mut class.body.body) {
+            match element {
+                ClassElement::PropertyDefinition(def) if predicate(&element) => {
+                    // Fields without an initializer are removed, unless `allow_declare_fields`
+                    // is disabled, which initializes type-only fields to `undefined`
+                    let PropertyDefinition { span, key, value, type_annotation, .. } = def.unbox();
+                    let value = value.or_else(|| {
+                        (!self.options.allow_declare_fields && type_annotation.is_some())
+                            .then(|| ctx.ast.void_0())
+                    });
+                    if let Some(value) = value {
+                        assignments.push(Self::create_this_field_assignment(span, key, value, ctx));
+                    }
+                }
+                element => elements.push(element),
+            }
+        }
+        class.body.body = elements;
+
+        if let Some(constructor) = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(def) if def.kind.is_constructor() => Some(&mut **def),
+            _ => None,
+        }) {
+            let body = constructor.value.body.get_or_insert_with(|| {
+                ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec())
+            });
+            body.statements.splice(insert_index..insert_index, assignments);
+        } else {
+            let constructor = Self::create_constructor(class, is_derived, assignments, ctx);
+            class.body.body.insert(0, constructor);
+        }
+    }
+
+    /// `x = 1` is moved to the constructor, `static x = 1`, `#x = 1`, `[x] = 1` and `@dec x = 1` are not.
+    fn is_constructor_initialized_field(element: &ClassElement<'a>) -> bool {
+        let ClassElement::PropertyDefinition(def) = element else { return false };
+        matches!(def.r#type, PropertyDefinitionType::PropertyDefinition)
+            && !def.r#static
+            && !def.declare
+            && def.decorators.is_empty()
+            && matches!(
+                def.key,
+                PropertyKey::StaticIdentifier(_)
+                    | PropertyKey::StringLiteral(_)
+                    | PropertyKey::NumericLiteral(_)
+            )
+    }
+
+    /// `x: number` and `static x: number`. Fields with private or computed keys and decorated fields
+    /// are kept as they are, since they may be needed at runtime.
+    fn is_type_only_field(element: &ClassElement<'a>) -> bool {
+        let ClassElement::PropertyDefinition(def) = element else { return false };
+        matches!(def.r#type, PropertyDefinitionType::PropertyDefinition)
+            && def.value.is_none()
+            && def.type_annotation.is_some()
+            && !def.declare
+            && def.decorators.is_empty()
+            && matches!(
+                def.key,
+                PropertyKey::StaticIdentifier(_)
+                    | PropertyKey::StringLiteral(_)
+                    | PropertyKey::NumericLiteral(_)
+            )
+    }
+
+    /// `x = 1` -> `this.x = 1`, `"a-b" = 1` -> `this["a-b"] = 1`
+    fn create_this_field_assignment(
+        span: Span,
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let object = ctx.ast.expression_this(SPAN);
+        let target = match key {
+            PropertyKey::StaticIdentifier(id) => {
+                ctx.ast.member_expression_static(id.span, object, id.unbox(), false)
+            }
+            key => {
+                let key = key.into_expression();
+                ctx.ast.member_expression_computed(key.span(), object, key, false)
+            }
+        };
+        let assignment = ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            ctx.ast.simple_assignment_target_member_expression(target).into(),
+            value,
+        );
+        ctx.ast.statement_expression(span, assignment)
+    }
+
+    /// `constructor() { ... }`, or `constructor(..._args) { super(..._args); ... }` in a derived class
+    fn create_constructor(
+        class: &Class<'a>,
+        is_derived: bool,
+        mut statements: ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope(
+            class.scope_id.get().unwrap(),
+            ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+        );
+
+        let rest = if is_derived {
+            let binding = BoundIdentifier::new_uid(
+                "args",
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+                ctx,
+            );
+            let argument =
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+            let arguments = ctx.ast.vec1(ctx.ast.argument_spread_element(SPAN, argument));
+            let super_call = ctx.ast.expression_call(
+                SPAN,
+                ctx.ast.expression_super(SPAN),
+                NONE,
+                arguments,
+                false,
+            );
+            statements.insert(0, ctx.ast.statement_expression(SPAN, super_call));
+
+            let pattern = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(
+                    binding.create_binding_identifier(),
+                ),
+                NONE,
+                false,
+            );
+            Some(ctx.ast.alloc_binding_rest_element(SPAN, pattern))
+        } else {
+            None
+        };
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            rest,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let function = ctx.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        );
+        function.scope_id.set(Some(scope_id));
+
+        ctx.ast.class_element_method_definition(
+            MethodDefinitionType::MethodDefinition,
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.property_key_identifier_name(SPAN, "constructor"),
+            function,
+            MethodDefinitionKind::Constructor,
+            false,
+            false,
+            false,
+            false,
+            None,
+        )
+    }
+
     fn create_block_with_statement(
         stmt: Statement<'a>,
         span: Span,
@@ -595,6 +1045,7 @@ impl<'a> TypeScriptAnnotations<'a> {
             if has_value_redeclaration {
                 return false;
             }
+            // References in type positions, including `typeof T` type queries, are type-only
             if ctx
                 .symbols()
                 .get_resolved_references(symbol_id)
@@ -606,6 +1057,18 @@ impl<'a> TypeScriptAnnotations<'a> {
 
         self.is_jsx_imports(name)
     }
+
+    /// The opposite of [`Self::has_value_reference`], for referenced imports:
+    /// `import { T } from 'mod'; let x: T;`
+    fn has_only_type_references(&self, name: &str, ctx: &TraverseCtx<'a>) -> bool {
+        let Some(symbol_id) = ctx.scopes().get_root_binding(name) else {
+            return false;
+        };
+        let mut references = ctx.symbols().get_resolved_references(symbol_id).peekable();
+        references.peek().is_some()
+            && references.all(Reference::is_type)
+            && !self.is_jsx_imports(name)
+    }
 }
 
 struct Assignment<'a> {
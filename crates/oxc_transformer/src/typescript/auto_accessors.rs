@@ -0,0 +1,360 @@
+use oxc_allocator::{CloneIn, Vec};
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    node::NodeId,
+    operator::AssignmentOperator,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::helpers::bindings::BoundIdentifier;
+
+/// Lower auto-accessors to a private field with a getter and setter, for targets without native
+/// support, the same as TypeScript does for targets below ES2022.
+///
+/// In:
+/// ```js
+/// class Foo {
+///   @dec accessor x = 1;
+///   static accessor [key()];
+/// }
+/// ```
+///
+/// Out:
+/// ```js
+/// var _key;
+/// class Foo {
+///   #x_accessor_storage = 1;
+///   @dec get x() { return this.#x_accessor_storage; }
+///   set x(value) { this.#x_accessor_storage = value; }
+///   static #_key_accessor_storage;
+///   static get [_key = key()]() { return this.#_key_accessor_storage; }
+///   static set [_key](value) { this.#_key_accessor_storage = value; }
+/// }
+/// ```
+///
+/// Decorators of the accessor are moved to the getter, so legacy decorators still decorate
+/// the property descriptor with both the getter and setter.
+pub struct TypeScriptAutoAccessors<'a> {
+    /// Declarations of the computed keys, for each statements list
+    var_declarations: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> TypeScriptAutoAccessors<'a> {
+    pub fn new() -> Self {
+        Self { var_declarations: vec![] }
+    }
+}
+
+impl<'a> Traverse<'a> for TypeScriptAutoAccessors<'a> {
+    fn enter_statements(&mut self, _stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        self.var_declarations.push(ctx.ast.vec());
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(declarations) = self.var_declarations.pop() {
+            if declarations.is_empty() {
+                return;
+            }
+            let kind = VariableDeclarationKind::Var;
+            let decl = ctx.ast.declaration_variable(SPAN, kind, declarations, false);
+            stmts.insert(0, Statement::from(decl));
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let is_auto_accessor = |element: &ClassElement<'a>| {
+            matches!(element, ClassElement::AccessorProperty(prop)
+                if prop.r#type == AccessorPropertyType::AccessorProperty)
+        };
+        if !class.body.body.iter().any(is_auto_accessor) {
+            return;
+        }
+
+        let scope_id = class.scope_id.get().unwrap();
+        let mut private_names = class
+            .body
+            .body
+            .iter()
+            .filter_map(|element| element.property_key()?.private_name())
+            .collect::<std::vec::Vec<_>>();
+
+        let mut body = ctx.ast.vec_with_capacity(class.body.body.len());
+        for element in ctx.ast.move_vec(&mut class.body.body) {
+            if !is_auto_accessor(&element) {
+                body.push(element);
+                continue;
+            }
+            let ClassElement::AccessorProperty(prop) = element else { unreachable!() };
+            let AccessorProperty { span, decorators, key, value, computed, r#static, .. } =
+                prop.unbox();
+
+            let (getter_key, setter_key, base_name) = self.split_key(key, computed, ctx);
+            let storage_name = Self::get_storage_name(&base_name, &mut private_names, ctx);
+
+            body.push(ctx.ast.class_element_property_definition(
+                PropertyDefinitionType::PropertyDefinition,
+                span,
+                ctx.ast.vec(),
+                ctx.ast.property_key_private_identifier(SPAN, storage_name.clone()),
+                value,
+                false,
+                r#static,
+                false,
+                false,
+                false,
+                false,
+                false,
+                NONE,
+                None,
+            ));
+            body.push(Self::create_getter(
+                decorators,
+                getter_key,
+                computed,
+                r#static,
+                &storage_name,
+                scope_id,
+                ctx,
+            ));
+            body.push(Self::create_setter(
+                setter_key,
+                computed,
+                r#static,
+                &storage_name,
+                scope_id,
+                ctx,
+            ));
+        }
+        class.body.body = body;
+    }
+}
+
+impl<'a> TypeScriptAutoAccessors<'a> {
+    /// Get the keys of the getter and setter, and the name to base the storage name on.
+    ///
+    /// Computed keys are evaluated once: `accessor [foo()]` -> `get [_foo = foo()]()` and `set [_foo]()`
+    fn split_key(
+        &mut self,
+        mut key: PropertyKey<'a>,
+        computed: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (PropertyKey<'a>, PropertyKey<'a>, Atom<'a>) {
+        let base_name = match &key {
+            PropertyKey::StaticIdentifier(ident) => ident.name.clone(),
+            PropertyKey::PrivateIdentifier(ident) => ident.name.clone(),
+            _ => Atom::from(""),
+        };
+        let is_literal = matches!(
+            key,
+            PropertyKey::StaticIdentifier(_)
+                | PropertyKey::PrivateIdentifier(_)
+                | PropertyKey::StringLiteral(_)
+                | PropertyKey::NumericLiteral(_)
+        );
+        if !computed || is_literal {
+            let setter_key = key.clone_in(ctx.ast.allocator);
+            return (key, setter_key, base_name);
+        }
+
+        let expr = ctx.ast.move_expression(key.to_expression_mut());
+        let symbol_id = ctx.generate_uid_in_current_scope_based_on_node(
+            &expr,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let name = ctx.ast.atom(&ctx.symbols().names[symbol_id]);
+        let binding = BoundIdentifier { name, symbol_id };
+
+        let kind = ctx
+            .ast
+            .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier());
+        let id = ctx.ast.binding_pattern(kind, NONE, false);
+        let declarator =
+            ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, None, false);
+        if let Some(declarations) = self.var_declarations.last_mut() {
+            declarations.push(declarator);
+        }
+
+        // `_foo = foo()`
+        let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+            binding.create_write_reference(ctx),
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            expr,
+        );
+        let setter_key =
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        (PropertyKey::from(assignment), PropertyKey::from(setter_key), binding.name)
+    }
+
+    /// `#x_accessor_storage`, made unique among the private names of the class
+    fn get_storage_name(
+        base_name: &str,
+        private_names: &mut std::vec::Vec<Atom<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Atom<'a> {
+        let prefix = if base_name.is_empty() {
+            String::from("accessor_storage")
+        } else {
+            format!("{base_name}_accessor_storage")
+        };
+        let mut name = prefix.clone();
+        let mut i = 1;
+        while private_names.iter().any(|private_name| private_name.as_str() == name) {
+            i += 1;
+            name = format!("{prefix}_{i}");
+        }
+        let name = ctx.ast.atom(&name);
+        private_names.push(name.clone());
+        name
+    }
+
+    /// `this.#x_accessor_storage`
+    fn create_storage_member(
+        storage_name: &Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        let field = ctx.ast.private_identifier(SPAN, storage_name.clone());
+        ctx.ast.member_expression_private_field_expression(
+            SPAN,
+            ctx.ast.expression_this(SPAN),
+            field,
+            false,
+        )
+    }
+
+    /// `get x() { return this.#x_accessor_storage; }`
+    fn create_getter(
+        decorators: Vec<'a, Decorator<'a>>,
+        key: PropertyKey<'a>,
+        computed: bool,
+        r#static: bool,
+        storage_name: &Atom<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::GetAccessor | ScopeFlags::StrictMode,
+        );
+        let storage = Expression::from(Self::create_storage_member(storage_name, ctx));
+        let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(storage)));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        Self::create_method(
+            decorators,
+            key,
+            MethodDefinitionKind::Get,
+            computed,
+            r#static,
+            params,
+            body,
+            scope_id,
+            ctx,
+        )
+    }
+
+    /// `set x(value) { this.#x_accessor_storage = value; }`
+    fn create_setter(
+        key: PropertyKey<'a>,
+        computed: bool,
+        r#static: bool,
+        storage_name: &Atom<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::SetAccessor | ScopeFlags::StrictMode,
+        );
+        let name = Atom::from("value");
+        let symbol_id = ctx.symbols_mut().create_symbol(
+            SPAN,
+            name.to_compact_str(),
+            SymbolFlags::FunctionScopedVariable,
+            scope_id,
+            NodeId::DUMMY,
+        );
+        ctx.scopes_mut().add_binding(scope_id, name.to_compact_str(), symbol_id);
+        let binding = BoundIdentifier { name, symbol_id };
+
+        let target = SimpleAssignmentTarget::from(Self::create_storage_member(storage_name, ctx));
+        let value =
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            value,
+        );
+        let body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment));
+
+        let kind = ctx
+            .ast
+            .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier());
+        let param =
+            ctx.ast.plain_formal_parameter(SPAN, ctx.ast.binding_pattern(kind, NONE, false));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        Self::create_method(
+            ctx.ast.vec(),
+            key,
+            MethodDefinitionKind::Set,
+            computed,
+            r#static,
+            params,
+            body,
+            scope_id,
+            ctx,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_method(
+        decorators: Vec<'a, Decorator<'a>>,
+        key: PropertyKey<'a>,
+        kind: MethodDefinitionKind,
+        computed: bool,
+        r#static: bool,
+        params: FormalParameters<'a>,
+        body: Vec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), body);
+        let function = ctx.ast.plain_function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            Some(body),
+        );
+        function.scope_id.set(Some(scope_id));
+        ctx.ast.class_element_method_definition(
+            MethodDefinitionType::MethodDefinition,
+            SPAN,
+            decorators,
+            key,
+            function,
+            kind,
+            computed,
+            r#static,
+            false,
+            false,
+            None,
+        )
+    }
+}
@@ -0,0 +1,213 @@
+use oxc_ast::ast::*;
+use oxc_syntax::{
+    number::{ToJsInt32, ToJsString},
+    operator::{BinaryOperator, UnaryOperator},
+};
+
+#[derive(Debug, Clone)]
+pub enum ConstantValue {
+    Number(f64),
+    String(String),
+}
+
+/// Evaluate the expression to a constant value, or `None` if it can only be computed at runtime.
+///
+/// Identifiers and member expressions other than `Infinity` and `NaN` are passed to `resolve`,
+/// which returns their values if they are known, e.g. previous enum members (`A`) or members
+/// of other enums (`Other.A`).
+///
+/// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
+pub fn eval_ts_const_expr<'a, F>(expr: &Expression<'a>, resolve: &F) -> Option<ConstantValue>
+where
+    F: Fn(&Expression<'a>) -> Option<ConstantValue>,
+{
+    match expr {
+        Expression::Identifier(ident) if ident.name == "Infinity" => {
+            Some(ConstantValue::Number(f64::INFINITY))
+        }
+        Expression::Identifier(ident) if ident.name == "NaN" => {
+            Some(ConstantValue::Number(f64::NAN))
+        }
+        Expression::Identifier(_)
+        | Expression::ComputedMemberExpression(_)
+        | Expression::StaticMemberExpression(_)
+        | Expression::PrivateFieldExpression(_) => resolve(expr),
+        Expression::BinaryExpression(expr) => eval_binary_expression(expr, resolve),
+        Expression::UnaryExpression(expr) => eval_unary_expression(expr, resolve),
+        Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+        Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
+        Expression::TemplateLiteral(lit) => {
+            let mut value = String::new();
+            for (i, quasi) in lit.quasis.iter().enumerate() {
+                value.push_str(quasi.value.cooked.as_ref()?);
+                if let Some(expr) = lit.expressions.get(i) {
+                    match eval_ts_const_expr(expr, resolve)? {
+                        ConstantValue::Number(v) => value.push_str(&v.to_js_string()),
+                        ConstantValue::String(str) => value.push_str(&str),
+                    }
+                }
+            }
+            Some(ConstantValue::String(value))
+        }
+        Expression::ParenthesizedExpression(expr) => eval_ts_const_expr(&expr.expression, resolve),
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+fn eval_binary_expression<'a, F>(expr: &BinaryExpression<'a>, resolve: &F) -> Option<ConstantValue>
+where
+    F: Fn(&Expression<'a>) -> Option<ConstantValue>,
+{
+    let left = eval_ts_const_expr(&expr.left, resolve)?;
+    let right = eval_ts_const_expr(&expr.right, resolve)?;
+
+    if matches!(expr.operator, BinaryOperator::Addition)
+        && (matches!(left, ConstantValue::String(_)) || matches!(right, ConstantValue::String(_)))
+    {
+        let left_string = match left {
+            ConstantValue::String(str) => str,
+            ConstantValue::Number(v) => v.to_js_string(),
+        };
+
+        let right_string = match right {
+            ConstantValue::String(str) => str,
+            ConstantValue::Number(v) => v.to_js_string(),
+        };
+
+        return Some(ConstantValue::String(format!("{left_string}{right_string}")));
+    }
+
+    let left = match left {
+        ConstantValue::Number(v) => v,
+        ConstantValue::String(_) => return None,
+    };
+
+    let right = match right {
+        ConstantValue::Number(v) => v,
+        ConstantValue::String(_) => return None,
+    };
+
+    match expr.operator {
+        BinaryOperator::ShiftRight => Some(ConstantValue::Number(f64::from(
+            left.to_js_int_32().wrapping_shr(right.to_js_int_32() as u32),
+        ))),
+        BinaryOperator::ShiftRightZeroFill => Some(ConstantValue::Number(f64::from(
+            (left.to_js_int_32() as u32).wrapping_shr(right.to_js_int_32() as u32),
+        ))),
+        BinaryOperator::ShiftLeft => Some(ConstantValue::Number(f64::from(
+            left.to_js_int_32().wrapping_shl(right.to_js_int_32() as u32),
+        ))),
+        BinaryOperator::BitwiseXOR => {
+            Some(ConstantValue::Number(f64::from(left.to_js_int_32() ^ right.to_js_int_32())))
+        }
+        BinaryOperator::BitwiseOR => {
+            Some(ConstantValue::Number(f64::from(left.to_js_int_32() | right.to_js_int_32())))
+        }
+        BinaryOperator::BitwiseAnd => {
+            Some(ConstantValue::Number(f64::from(left.to_js_int_32() & right.to_js_int_32())))
+        }
+        BinaryOperator::Multiplication => Some(ConstantValue::Number(left * right)),
+        BinaryOperator::Division => Some(ConstantValue::Number(left / right)),
+        BinaryOperator::Addition => Some(ConstantValue::Number(left + right)),
+        BinaryOperator::Subtraction => Some(ConstantValue::Number(left - right)),
+        BinaryOperator::Remainder => Some(ConstantValue::Number(left % right)),
+        BinaryOperator::Exponential => Some(ConstantValue::Number(left.powf(right))),
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn eval_unary_expression<'a, F>(expr: &UnaryExpression<'a>, resolve: &F) -> Option<ConstantValue>
+where
+    F: Fn(&Expression<'a>) -> Option<ConstantValue>,
+{
+    let value = eval_ts_const_expr(&expr.argument, resolve)?;
+
+    let value = match value {
+        ConstantValue::Number(value) => value,
+        ConstantValue::String(_) => {
+            let value = if expr.operator == UnaryOperator::UnaryNegation {
+                ConstantValue::Number(f64::NAN)
+            } else if expr.operator == UnaryOperator::BitwiseNot {
+                ConstantValue::Number(-1.0)
+            } else {
+                value
+            };
+            return Some(value);
+        }
+    };
+
+    match expr.operator {
+        UnaryOperator::UnaryPlus => Some(ConstantValue::Number(value)),
+        UnaryOperator::UnaryNegation => Some(ConstantValue::Number(-value)),
+        UnaryOperator::BitwiseNot => Some(ConstantValue::Number(f64::from(!value.to_js_int_32()))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Expression;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{eval_ts_const_expr, ConstantValue};
+
+    fn eval(source_text: &str) -> Option<ConstantValue> {
+        let allocator = Allocator::default();
+        let expr =
+            Parser::new(&allocator, source_text, SourceType::ts()).parse_expression().unwrap();
+        // `A` is a previous member, `Other.B` a member of another enum
+        eval_ts_const_expr(&expr, &|expr| match expr {
+            Expression::Identifier(ident) if ident.name == "A" => Some(ConstantValue::Number(8.0)),
+            Expression::StaticMemberExpression(member) if member.property.name == "B" => {
+                Some(ConstantValue::String("b".to_string()))
+            }
+            _ => None,
+        })
+    }
+
+    fn number(source_text: &str) -> f64 {
+        match eval(source_text) {
+            Some(ConstantValue::Number(v)) => v,
+            value => panic!("{source_text}: {value:?}"),
+        }
+    }
+
+    fn string(source_text: &str) -> String {
+        match eval(source_text) {
+            Some(ConstantValue::String(v)) => v,
+            value => panic!("{source_text}: {value:?}"),
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn numbers() {
+        assert_eq!(number("1 << 3"), 8.0);
+        assert_eq!(number("-1 >>> 28"), 15.0);
+        assert_eq!(number("~A & 0xff | 1 ^ 3"), 247.0);
+        assert_eq!(number("(A + 1) * 2 / 4 % 3"), 1.5);
+        assert_eq!(number("2 ** 32"), 4_294_967_296.0);
+        assert!(number("-Infinity").is_infinite());
+        assert!(number("NaN").is_nan());
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(string("'a' + Other.B"), "ab");
+        assert_eq!(string("`${A}-${Other.B}`"), "8-b");
+        assert_eq!(string("'x' + 2.5"), "x2.5");
+    }
+
+    #[test]
+    fn non_constant() {
+        assert!(eval("foo").is_none());
+        assert!(eval("A + foo()").is_none());
+        assert!(eval("Other.C").is_none());
+        assert!(eval("'a' * 2").is_none());
+        assert!(eval("a ? 1 : 2").is_none());
+    }
+}
@@ -0,0 +1,357 @@
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{BinaryOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{context::Ctx, helpers::runtime_helpers::RuntimeHelper};
+
+/// Emit design-time type metadata for decorated classes and class members,
+/// the same as TypeScript's `emitDecoratorMetadata` option.
+///
+/// The metadata decorators are appended after the user-written decorators.
+///
+/// In:
+/// ```ts
+/// class Foo {
+///   @dec
+///   method(a: string): number {}
+/// }
+/// ```
+///
+/// Out:
+/// ```js
+/// class Foo {
+///   @dec
+///   @_metadata("design:type", Function)
+///   @_metadata("design:paramtypes", [String])
+///   @_metadata("design:returntype", Number)
+///   method(a) {}
+/// }
+/// ```
+///
+/// The `_metadata` helper is injected at the top of the file when used,
+/// see [`RuntimeHelpers`](crate::helpers::runtime_helpers::RuntimeHelpers).
+pub struct TypeScriptDecoratorMetadata<'a> {
+    ctx: Ctx<'a>,
+}
+
+impl<'a> TypeScriptDecoratorMetadata<'a> {
+    pub fn new(ctx: Ctx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a> for TypeScriptDecoratorMetadata<'a> {
+    // This needs to run before type annotations of the class members are removed.
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let constructor = class.body.body.iter().find_map(|element| match element {
+            ClassElement::MethodDefinition(def)
+                if def.kind.is_constructor() && def.value.body.is_some() =>
+            {
+                Some(def)
+            }
+            _ => None,
+        });
+        if let Some(constructor) = constructor {
+            if !class.decorators.is_empty() || has_parameter_decorators(&constructor.value.params) {
+                let param_types = Self::serialize_parameter_types(&constructor.value.params, ctx);
+                class.decorators.push(self.create_metadata("design:paramtypes", param_types, ctx));
+            }
+        }
+
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(def) => {
+                    if def.kind.is_constructor()
+                        || (def.decorators.is_empty()
+                            && !has_parameter_decorators(&def.value.params))
+                    {
+                        continue;
+                    }
+                    let func = &def.value;
+                    let metadata = match def.kind {
+                        MethodDefinitionKind::Get => {
+                            let design_type =
+                                Self::serialize_type_annotation(func.return_type.as_deref(), ctx);
+                            ctx.ast.vec1(self.create_metadata("design:type", design_type, ctx))
+                        }
+                        MethodDefinitionKind::Set => {
+                            let design_type = Self::serialize_type_annotation(
+                                func.params
+                                    .items
+                                    .first()
+                                    .and_then(|param| param.pattern.type_annotation.as_deref()),
+                                ctx,
+                            );
+                            let param_types = Self::serialize_parameter_types(&func.params, ctx);
+                            ctx.ast.vec_from_iter([
+                                self.create_metadata("design:type", design_type, ctx),
+                                self.create_metadata("design:paramtypes", param_types, ctx),
+                            ])
+                        }
+                        _ => {
+                            let design_type = Self::create_global_reference("Function", ctx);
+                            let param_types = Self::serialize_parameter_types(&func.params, ctx);
+                            let return_type = Self::serialize_return_type(func, ctx);
+                            ctx.ast.vec_from_iter([
+                                self.create_metadata("design:type", design_type, ctx),
+                                self.create_metadata("design:paramtypes", param_types, ctx),
+                                self.create_metadata("design:returntype", return_type, ctx),
+                            ])
+                        }
+                    };
+                    def.decorators.extend(metadata);
+                }
+                ClassElement::PropertyDefinition(def) if !def.decorators.is_empty() => {
+                    let design_type =
+                        Self::serialize_type_annotation(def.type_annotation.as_deref(), ctx);
+                    def.decorators.push(self.create_metadata("design:type", design_type, ctx));
+                }
+                ClassElement::AccessorProperty(def) if !def.decorators.is_empty() => {
+                    let design_type =
+                        Self::serialize_type_annotation(def.type_annotation.as_deref(), ctx);
+                    def.decorators.push(self.create_metadata("design:type", design_type, ctx));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'a> TypeScriptDecoratorMetadata<'a> {
+    /// `@_metadata(key, value)`
+    fn create_metadata(
+        &self,
+        key: &'static str,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Decorator<'a> {
+        let callee = self.ctx.runtime_helpers.create_reference(RuntimeHelper::Metadata, ctx);
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_string_literal(SPAN, key)),
+            Argument::from(value),
+        ]);
+        let expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        ctx.ast.decorator(SPAN, expr)
+    }
+
+    fn create_global_reference(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+        let ident =
+            ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+        ctx.ast.expression_from_identifier_reference(ident)
+    }
+
+    /// `(a: string, b: Foo, ...rest: number[])` -> `[String, Foo, Number]`
+    fn serialize_parameter_types(
+        params: &FormalParameters<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut elements = ctx.ast.vec_with_capacity(params.parameters_count());
+        for param in &params.items {
+            let expr =
+                Self::serialize_type_annotation(param.pattern.type_annotation.as_deref(), ctx);
+            elements.push(ArrayExpressionElement::from(expr));
+        }
+        if let Some(rest) = &params.rest {
+            let expr = match rest.argument.type_annotation.as_deref() {
+                Some(TSTypeAnnotation { type_annotation: TSType::TSArrayType(array), .. }) => {
+                    Self::serialize_type(&array.element_type, ctx)
+                }
+                _ => Self::create_global_reference("Object", ctx),
+            };
+            elements.push(ArrayExpressionElement::from(expr));
+        }
+        ctx.ast.expression_array(SPAN, elements, None)
+    }
+
+    fn serialize_return_type(func: &Function<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if let Some(return_type) = &func.return_type {
+            Self::serialize_type(&return_type.type_annotation, ctx)
+        } else if func.r#async {
+            Self::create_global_reference("Promise", ctx)
+        } else {
+            ctx.ast.void_0()
+        }
+    }
+
+    fn serialize_type_annotation(
+        type_annotation: Option<&TSTypeAnnotation<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match type_annotation {
+            Some(type_annotation) => Self::serialize_type(&type_annotation.type_annotation, ctx),
+            None => Self::create_global_reference("Object", ctx),
+        }
+    }
+
+    fn serialize_type(ts_type: &TSType<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match SerializedType::from_type(ts_type, ctx) {
+            SerializedType::Void => ctx.ast.void_0(),
+            SerializedType::Global(name) => Self::create_global_reference(name, ctx),
+            SerializedType::Reference(type_name) => {
+                Self::create_type_name_reference(type_name, ctx)
+            }
+            SerializedType::Unresolved(type_name) => {
+                Self::create_unresolved_type_name_reference(type_name, ctx)
+            }
+        }
+    }
+
+    /// `Foo.Bar` -> `typeof Foo === "undefined" ? Object : Foo.Bar`
+    ///
+    /// The name may only exist as a type, e.g. `Record`, so referencing it would throw.
+    fn create_unresolved_type_name_reference(
+        type_name: &TSTypeName<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ident = TSTypeName::get_first_name(type_name);
+        let test = {
+            let reference =
+                ctx.create_unbound_reference_id(SPAN, ident.name.clone(), ReferenceFlags::Read);
+            let argument = ctx.ast.expression_from_identifier_reference(reference);
+            let left = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, argument);
+            let right = ctx.ast.expression_string_literal(SPAN, "undefined");
+            ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+        };
+        let consequent = Self::create_global_reference("Object", ctx);
+        let alternate = Self::create_type_name_reference(type_name, ctx);
+        ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+    }
+
+    /// `A.B.C` -> `A.B.C`
+    fn create_type_name_reference(
+        type_name: &TSTypeName<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match type_name {
+            TSTypeName::IdentifierReference(ident) => {
+                let symbol_id = ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+                let ident = ctx.create_reference_id(
+                    SPAN,
+                    ident.name.clone(),
+                    symbol_id,
+                    ReferenceFlags::Read,
+                );
+                ctx.ast.expression_from_identifier_reference(ident)
+            }
+            TSTypeName::QualifiedName(qualified_name) => {
+                let object = Self::create_type_name_reference(&qualified_name.left, ctx);
+                let property = qualified_name.right.clone();
+                Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+            }
+        }
+    }
+}
+
+/// The runtime value a type is serialized to, following the rules of `tsc`.
+enum SerializedType<'a, 'b> {
+    /// `void 0`
+    Void,
+    /// A global constructor, e.g. `Number`
+    Global(&'static str),
+    /// A type reference that resolves to a value, e.g. a class
+    Reference(&'b TSTypeName<'a>),
+    /// A type reference that doesn't resolve, e.g. `Date` or `Record`
+    Unresolved(&'b TSTypeName<'a>),
+}
+
+impl<'a, 'b> SerializedType<'a, 'b> {
+    fn from_type(ts_type: &'b TSType<'a>, ctx: &TraverseCtx<'a>) -> Self {
+        match ts_type {
+            TSType::TSVoidKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSNeverKeyword(_)
+            | TSType::TSNullKeyword(_) => Self::Void,
+            TSType::TSParenthesizedType(ty) => Self::from_type(&ty.type_annotation, ctx),
+            TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => Self::Global("Function"),
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => Self::Global("Array"),
+            TSType::TSTypePredicate(_) | TSType::TSBooleanKeyword(_) => Self::Global("Boolean"),
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => Self::Global("String"),
+            TSType::TSNumberKeyword(_) => Self::Global("Number"),
+            TSType::TSBigIntKeyword(_) => Self::Global("BigInt"),
+            TSType::TSSymbolKeyword(_) => Self::Global("Symbol"),
+            TSType::TSLiteralType(ty) => match &ty.literal {
+                TSLiteral::BooleanLiteral(_) => Self::Global("Boolean"),
+                TSLiteral::NumericLiteral(_) | TSLiteral::UnaryExpression(_) => {
+                    Self::Global("Number")
+                }
+                TSLiteral::BigIntLiteral(_) => Self::Global("BigInt"),
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                    Self::Global("String")
+                }
+                TSLiteral::NullLiteral(_) => Self::Void,
+                TSLiteral::RegExpLiteral(_) => Self::Global("Object"),
+            },
+            TSType::TSTypeReference(ty) => Self::from_type_name(&ty.type_name, ctx),
+            TSType::TSUnionType(ty) => Self::from_type_list(&ty.types, ctx),
+            TSType::TSIntersectionType(ty) => Self::from_type_list(&ty.types, ctx),
+            _ => Self::Global("Object"),
+        }
+    }
+
+    /// Only type references resolving to a value can be referenced at runtime,
+    /// unresolved references may be globals, e.g. `Date`, or types only, e.g. `Record`.
+    fn from_type_name(type_name: &'b TSTypeName<'a>, ctx: &TraverseCtx<'a>) -> Self {
+        let ident = TSTypeName::get_first_name(type_name);
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+        let Some(symbol_id) = symbol_id else {
+            return Self::Unresolved(type_name);
+        };
+        let flags = ctx.symbols().get_flags(symbol_id);
+        if !flags.is_enum()
+            && flags.intersects(
+                SymbolFlags::Class
+                    | SymbolFlags::Variable
+                    | SymbolFlags::Function
+                    | SymbolFlags::Import
+                    | SymbolFlags::ValueModule,
+            )
+        {
+            Self::Reference(type_name)
+        } else {
+            Self::Global("Object")
+        }
+    }
+
+    /// `Foo | null | undefined` -> `Foo`, `string | number` -> `Object`
+    fn from_type_list(types: &'b [TSType<'a>], ctx: &TraverseCtx<'a>) -> Self {
+        let mut serialized: Option<Self> = None;
+        for ty in types {
+            let current = Self::from_type(ty, ctx);
+            // Skip `null`, `undefined` and `never`
+            if matches!(current, Self::Void) {
+                continue;
+            }
+            match &serialized {
+                Some(prev) if !prev.is_same(&current) => return Self::Global("Object"),
+                Some(_) => {}
+                None => serialized = Some(current),
+            }
+        }
+        serialized.unwrap_or(Self::Void)
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Global(a), Self::Global(b)) => a == b,
+            (Self::Reference(a), Self::Reference(b))
+            | (Self::Unresolved(a), Self::Unresolved(b)) => a.to_string() == b.to_string(),
+            _ => false,
+        }
+    }
+}
+
+fn has_parameter_decorators(params: &FormalParameters) -> bool {
+    params.items.iter().any(|param| !param.decorators.is_empty())
+}
@@ -0,0 +1,459 @@
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_syntax::{node::NodeId, operator::AssignmentOperator, symbol::SymbolFlags};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use super::diagnostics;
+use crate::{
+    context::Ctx,
+    helpers::{
+        bindings::BoundIdentifier, number::create_number_literal, runtime_helpers::RuntimeHelper,
+    },
+};
+
+/// Lower legacy (experimental) decorators, the same as TypeScript's `experimentalDecorators` option.
+///
+/// In:
+/// ```ts
+/// @dec
+/// class Foo {
+///   @prop x;
+///   @method m(@param a) {}
+///   @method static s() {}
+/// }
+/// ```
+///
+/// Out:
+/// ```js
+/// let Foo = class Foo {
+///   x;
+///   m(a) {}
+///   static s() {}
+/// };
+/// _decorate([prop], Foo.prototype, "x", void 0);
+/// _decorate([method, _param(0, param)], Foo.prototype, "m", null);
+/// _decorate([method], Foo, "s", null);
+/// Foo = _decorate([dec], Foo);
+/// ```
+///
+/// The `_decorate` and `_param` helpers are injected at the top of the file when used,
+/// see [`RuntimeHelpers`](crate::helpers::runtime_helpers::RuntimeHelpers).
+///
+/// Decorators of class expressions and of members with private names are reported as errors,
+/// as they can't be applied with `_decorate`.
+pub struct TypeScriptDecorators<'a> {
+    ctx: Ctx<'a>,
+}
+
+impl<'a> TypeScriptDecorators<'a> {
+    pub fn new(ctx: Ctx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a> for TypeScriptDecorators<'a> {
+    // Parameter decorators are applied together with the decorators of their method,
+    // or with the class decorators for constructor parameters.
+    // `m(@dec a) {}` -> `@_param(0, dec) m(a) {}`
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Only class declarations are lowered, decorators of class expressions are reported
+        // as TypeScript does
+        let is_expression = class.is_expression();
+        if is_expression {
+            self.report_invalid_decorators(&class.decorators);
+        }
+        for element in class.body.body.iter_mut() {
+            // `_decorate` can't access members with private names
+            if is_expression
+                || element.property_key().is_some_and(PropertyKey::is_private_identifier)
+            {
+                self.report_invalid_member_decorators(element);
+                continue;
+            }
+            let ClassElement::MethodDefinition(def) = element else { continue };
+            let MethodDefinition { kind, value, decorators, .. } = &mut **def;
+            for (index, param) in value.params.items.iter_mut().enumerate() {
+                for decorator in ctx.ast.move_vec(&mut param.decorators) {
+                    let decorator = self.create_param_decorator(index, decorator, ctx);
+                    if kind.is_constructor() {
+                        class.decorators.push(decorator);
+                    } else {
+                        decorators.push(decorator);
+                    }
+                }
+            }
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if !stmts.iter().any(is_decorated_class_statement) {
+            return;
+        }
+
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in ctx.ast.move_vec(stmts) {
+            if !is_decorated_class_statement(&stmt) {
+                new_stmts.push(stmt);
+                continue;
+            }
+            match stmt {
+                Statement::ClassDeclaration(mut class) => {
+                    let binding = Self::get_class_binding(&mut class, ctx);
+                    let lowered = self.transform_class(&mut class, &binding, ctx);
+                    new_stmts.extend(lowered.key_declaration);
+                    new_stmts.push(if lowered.has_class_decorators {
+                        Statement::from(Self::create_class_declaration(class, &binding, ctx))
+                    } else {
+                        Statement::ClassDeclaration(class)
+                    });
+                    new_stmts.extend(lowered.decorations);
+                }
+                Statement::ExportNamedDeclaration(mut decl) => {
+                    let Some(Declaration::ClassDeclaration(class)) = &mut decl.declaration else {
+                        unreachable!()
+                    };
+                    let binding = Self::get_class_binding(class, ctx);
+                    let lowered = self.transform_class(class, &binding, ctx);
+                    if lowered.has_class_decorators {
+                        // `export class C {}` -> `export let C = class C {}`
+                        let Some(Declaration::ClassDeclaration(class)) = decl.declaration.take()
+                        else {
+                            unreachable!()
+                        };
+                        decl.declaration =
+                            Some(Self::create_class_declaration(class, &binding, ctx));
+                    }
+                    new_stmts.extend(lowered.key_declaration);
+                    new_stmts.push(Statement::ExportNamedDeclaration(decl));
+                    new_stmts.extend(lowered.decorations);
+                }
+                Statement::ExportDefaultDeclaration(mut decl) => {
+                    let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                        &mut decl.declaration
+                    else {
+                        unreachable!()
+                    };
+                    let binding = Self::get_class_binding(class, ctx);
+                    let lowered = self.transform_class(class, &binding, ctx);
+                    new_stmts.extend(lowered.key_declaration);
+                    if lowered.has_class_decorators {
+                        // `export default class C {}` -> `let C = class C {}; export default C;`
+                        // The export comes after the decorators, as it doesn't create a live binding.
+                        let reference = binding.create_read_reference(ctx);
+                        let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                            std::mem::replace(
+                                &mut decl.declaration,
+                                ExportDefaultDeclarationKind::from(
+                                    ctx.ast.expression_from_identifier_reference(reference),
+                                ),
+                            )
+                        else {
+                            unreachable!()
+                        };
+                        new_stmts.push(Statement::from(Self::create_class_declaration(
+                            class, &binding, ctx,
+                        )));
+                        new_stmts.extend(lowered.decorations);
+                        new_stmts.push(Statement::ExportDefaultDeclaration(decl));
+                    } else {
+                        new_stmts.push(Statement::ExportDefaultDeclaration(decl));
+                        new_stmts.extend(lowered.decorations);
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        *stmts = new_stmts;
+    }
+}
+
+/// Statements created from the decorators of a class.
+struct LoweredClass<'a> {
+    /// `var _key;` for computed keys of decorated members
+    key_declaration: Option<Statement<'a>>,
+    /// `_decorate(...)` calls for members, followed by the class decorators
+    decorations: Vec<'a, Statement<'a>>,
+    has_class_decorators: bool,
+}
+
+impl<'a> TypeScriptDecorators<'a> {
+    /// Get the binding of the class, anonymous default exported classes are given a name.
+    fn get_class_binding(class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        if let Some(id) = &class.id {
+            return BoundIdentifier {
+                name: id.name.clone(),
+                symbol_id: id.symbol_id.get().unwrap(),
+            };
+        }
+        let flags = if class.decorators.is_empty() {
+            SymbolFlags::Class
+        } else {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::Export
+        };
+        let binding = BoundIdentifier::new_uid_in_current_scope("default", flags, ctx);
+        if class.decorators.is_empty() {
+            // `export default class {}` -> `export default class _default {}`
+            class.id = Some(binding.create_binding_identifier());
+        }
+        binding
+    }
+
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> LoweredClass<'a> {
+        let mut keys = ctx.ast.vec();
+        let mut decorations = ctx.ast.vec();
+        let mut static_decorations = ctx.ast.vec();
+
+        for element in class.body.body.iter_mut() {
+            let (decorators, key, is_static, is_property) = match element {
+                ClassElement::MethodDefinition(def) => {
+                    let def = &mut **def;
+                    (&mut def.decorators, &mut def.key, def.r#static, false)
+                }
+                ClassElement::PropertyDefinition(def) => {
+                    let def = &mut **def;
+                    (&mut def.decorators, &mut def.key, def.r#static, true)
+                }
+                ClassElement::AccessorProperty(def) => {
+                    let def = &mut **def;
+                    (&mut def.decorators, &mut def.key, def.r#static, false)
+                }
+                _ => continue,
+            };
+            if decorators.is_empty() || key.is_private_identifier() {
+                continue;
+            }
+
+            let decorators = ctx.ast.move_vec(decorators);
+            let key = Self::get_member_key(key, &mut keys, ctx);
+            // `C` for static members, `C.prototype` for instance members
+            let class_reference =
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+            let target = if is_static {
+                class_reference
+            } else {
+                let property = ctx.ast.identifier_name(SPAN, "prototype");
+                Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    class_reference,
+                    property,
+                    false,
+                ))
+            };
+            // Properties are defined on the instance, so there's no descriptor to decorate
+            let descriptor =
+                if is_property { ctx.ast.void_0() } else { ctx.ast.expression_null_literal(SPAN) };
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(target),
+                Argument::from(key),
+                Argument::from(descriptor),
+            ]);
+            let call = self.create_decorate_call(decorators, arguments, ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, call);
+            if is_static {
+                static_decorations.push(stmt);
+            } else {
+                decorations.push(stmt);
+            }
+        }
+        decorations.extend(static_decorations);
+
+        // `C = _decorate([dec], C);`
+        let has_class_decorators = !class.decorators.is_empty();
+        if has_class_decorators {
+            let decorators = ctx.ast.move_vec(&mut class.decorators);
+            let class_reference =
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+            let arguments = ctx.ast.vec1(Argument::from(class_reference));
+            let call = self.create_decorate_call(decorators, arguments, ctx);
+            let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+                binding.create_write_reference(ctx),
+            );
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                call,
+            );
+            decorations.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+
+        let key_declaration = (!keys.is_empty()).then(|| {
+            let kind = VariableDeclarationKind::Var;
+            Statement::from(ctx.ast.declaration_variable(SPAN, kind, keys, false))
+        });
+
+        LoweredClass { key_declaration, decorations, has_class_decorators }
+    }
+
+    /// Get the key of a member, as passed to `_decorate`.
+    ///
+    /// Computed keys are evaluated once: `[foo()]() {}` -> `[_foo = foo()]() {}`
+    fn get_member_key(
+        key: &mut PropertyKey<'a>,
+        declarators: &mut Vec<'a, VariableDeclarator<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name.clone())
+            }
+            PropertyKey::StringLiteral(lit) => {
+                ctx.ast.expression_string_literal(SPAN, lit.value.clone())
+            }
+            PropertyKey::NumericLiteral(lit) => {
+                ctx.ast.expression_numeric_literal(SPAN, lit.value, lit.raw, lit.base)
+            }
+            _ => {
+                let expr = key.to_expression_mut();
+                let symbol_id = ctx.generate_uid_in_current_scope_based_on_node(
+                    expr,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                let name = ctx.ast.atom(&ctx.symbols().names[symbol_id]);
+                let binding = BoundIdentifier { name, symbol_id };
+
+                let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+                    binding.create_read_write_reference(ctx),
+                );
+                *expr = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    ctx.ast.move_expression(expr),
+                );
+
+                let kind = VariableDeclarationKind::Var;
+                let id = ctx.ast.binding_pattern(
+                    ctx.ast.binding_pattern_kind_from_binding_identifier(
+                        binding.create_binding_identifier(),
+                    ),
+                    NONE,
+                    false,
+                );
+                declarators.push(ctx.ast.variable_declarator(SPAN, kind, id, None, false));
+
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+            }
+        }
+    }
+
+    /// `_decorate([dec1, dec2], ...arguments)`
+    fn create_decorate_call(
+        &mut self,
+        decorators: Vec<'a, Decorator<'a>>,
+        mut arguments: Vec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = ctx.ast.vec_from_iter(
+            decorators
+                .into_iter()
+                .map(|decorator| ArrayExpressionElement::from(decorator.expression)),
+        );
+        arguments.insert(0, Argument::from(ctx.ast.expression_array(SPAN, elements, None)));
+        let callee = self.ctx.runtime_helpers.create_reference(RuntimeHelper::Decorate, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// Report the decorators of a member which can't be lowered, including its parameters'
+    fn report_invalid_member_decorators(&self, element: &ClassElement<'a>) {
+        match element {
+            ClassElement::MethodDefinition(def) => {
+                self.report_invalid_decorators(&def.decorators);
+                for param in &def.value.params.items {
+                    self.report_invalid_decorators(&param.decorators);
+                }
+            }
+            ClassElement::PropertyDefinition(def) => {
+                self.report_invalid_decorators(&def.decorators);
+            }
+            ClassElement::AccessorProperty(def) => self.report_invalid_decorators(&def.decorators),
+            _ => {}
+        }
+    }
+
+    fn report_invalid_decorators(&self, decorators: &[Decorator<'a>]) {
+        for decorator in decorators {
+            self.ctx.error(diagnostics::decorator_not_valid_here(decorator.span));
+        }
+    }
+
+    /// `@dec` on the parameter at `index` -> `@_param(index, dec)`
+    fn create_param_decorator(
+        &mut self,
+        index: usize,
+        decorator: Decorator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Decorator<'a> {
+        let callee = self.ctx.runtime_helpers.create_reference(RuntimeHelper::Param, ctx);
+        #[allow(clippy::cast_precision_loss)]
+        let index = create_number_literal(ctx.ast, SPAN, index as f64);
+        let arguments =
+            ctx.ast.vec_from_iter([Argument::from(index), Argument::from(decorator.expression)]);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        ctx.ast.decorator(decorator.span, call)
+    }
+
+    /// `class C {}` -> `let C = class C {}`
+    fn create_class_declaration(
+        mut class: Box<'a, Class<'a>>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Declaration<'a> {
+        class.r#type = ClassType::ClassExpression;
+        // The class name is now bound in the class scope, and the outer binding is a `let` variable
+        if let Some(id) = &class.id {
+            let scope_id = class.scope_id.get().unwrap();
+            let symbol_id = ctx.symbols_mut().create_symbol(
+                id.span,
+                id.name.to_compact_str(),
+                SymbolFlags::Class,
+                scope_id,
+                NodeId::DUMMY,
+            );
+            ctx.scopes_mut().add_binding(scope_id, id.name.to_compact_str(), symbol_id);
+            id.symbol_id.set(Some(symbol_id));
+        }
+        let flags = ctx.symbols_mut().get_flags_mut(binding.symbol_id);
+        *flags = SymbolFlags::BlockScopedVariable | (*flags & SymbolFlags::Export);
+        let kind = VariableDeclarationKind::Let;
+        let id = ctx.ast.binding_pattern(
+            ctx.ast
+                .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier()),
+            NONE,
+            false,
+        );
+        let init = Expression::ClassExpression(class);
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false)
+    }
+}
+
+/// Check if the statement is a class declaration with any decorators.
+fn is_decorated_class_statement(stmt: &Statement) -> bool {
+    let class = match stmt {
+        Statement::ClassDeclaration(class) => class,
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::ClassDeclaration(class)) => class,
+            _ => return false,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => class,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    !class.decorators.is_empty()
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(def) => !def.decorators.is_empty(),
+            ClassElement::PropertyDefinition(def) => !def.decorators.is_empty(),
+            ClassElement::AccessorProperty(def) => !def.decorators.is_empty(),
+            _ => false,
+        })
+}
@@ -25,3 +25,74 @@ pub fn namespace_not_supported(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Namespace not marked type-only declare. Non-declarative namespaces are only supported experimentally in Babel. To enable and review caveats see: https://babeljs.io/docs/en/babel-plugin-transform-typescript")
         .with_label(span)
 }
+
+pub fn computed_enum_member_name(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Computed property names are not allowed in enums.").with_label(span)
+}
+
+pub fn enum_member_numeric_name(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("An enum member cannot have a numeric name.").with_label(span)
+}
+
+pub fn enum_member_must_have_initializer(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Enum member must have initializer.").with_label(span)
+}
+
+pub fn const_enum_member_not_constant(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("const enum member initializers must be constant expressions.")
+        .with_help("Only literals, members of other enums and arithmetic on them can be inlined.")
+        .with_label(span)
+}
+
+pub fn ambient_const_enum_access(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Cannot access ambient const enums when 'isolatedModules' is enabled.")
+        .with_help("Only members with constant values can be accessed, e.g. `Foo.X`.")
+        .with_label(span)
+}
+
+pub fn type_re_export_requires_export_type(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Re-exporting a type when 'isolatedModules' is enabled requires using 'export type'.",
+    )
+    .with_label(span)
+}
+
+pub fn ambiguous_re_export(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` may be a type, which can't be re-exported without `export type` when compiling one file at a time."))
+        .with_help(format!("Use `export type {{ {name} }}` if `{name}` is a type."))
+        .with_label(span)
+}
+
+pub fn exported_const_enum(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Exported const enums can't be inlined in other modules when 'isolatedModules' is enabled.",
+    )
+    .with_help("Use a regular enum instead, or enable `preserveConstEnums`.")
+    .with_label(span)
+}
+
+pub fn import_only_used_as_type(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` is only used as a type and must be imported using a type-only import when 'verbatimModuleSyntax' is enabled."))
+        .with_help("Use `import type`, or add the `type` modifier to the specifier.")
+        .with_label(span)
+}
+
+pub fn import_not_used_as_value(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "This import is never used as a value and must use 'import type' because 'importsNotUsedAsValues' is set to 'error'.",
+    )
+    .with_label(span)
+}
+
+pub fn type_modifier_on_type_import(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "The 'type' modifier cannot be used on a named import when 'import type' is used on its import statement.",
+    )
+    .with_label(span)
+}
+
+pub fn decorator_not_valid_here(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Decorators are not valid here.")
+        .with_help("Legacy decorators can only decorate class declarations and their members without private names.")
+        .with_label(span)
+}
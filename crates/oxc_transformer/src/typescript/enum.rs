@@ -1,32 +1,315 @@
-use std::cell::Cell;
+use std::{cell::Cell, mem, rc::Rc};
 
 use oxc_allocator::Vec;
-use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
-use oxc_span::{Atom, Span, SPAN};
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut, NONE,
+};
+use oxc_span::{Atom, CompactStr, GetSpan, Span, SPAN};
 use oxc_syntax::{
     node::NodeId,
-    number::{NumberBase, ToJsInt32, ToJsString},
-    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
-    reference::ReferenceFlags,
-    symbol::SymbolFlags,
+    number::ToJsString,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    reference::{ReferenceFlags, ReferenceId},
+    scope::ScopeFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
-use oxc_traverse::{Traverse, TraverseCtx};
-use rustc_hash::FxHashMap;
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::context::Ctx;
+use super::{
+    const_eval::{eval_ts_const_expr, ConstantValue},
+    diagnostics,
+};
+use crate::{
+    context::Ctx,
+    helpers::number::{create_number, create_number_literal},
+    EnumOutputStyle, TypeScriptOptions,
+};
 
 pub struct TypeScriptEnum<'a> {
     ctx: Ctx<'a>,
-    enums: FxHashMap<Atom<'a>, FxHashMap<Atom<'a>, ConstantValue>>,
+    options: Rc<TypeScriptOptions>,
+    /// Constant members of the enums transformed so far, keyed by the enum's symbol.
+    /// Later enums can refer to them, e.g. `enum B { X = A.X }`.
+    enums: FxHashMap<SymbolId, FxHashMap<Atom<'a>, ConstantValue>>,
+    /// Evaluated members of every `const enum` in the program, keyed by the enum's symbol.
+    /// Only populated when `optimize_const_enums` is enabled, or for `declare const enum`s.
+    const_enums: FxHashMap<SymbolId, FxHashMap<Atom<'a>, ConstantValue>>,
+    /// `declare const enum`s have no runtime object, so any access to them which can't be
+    /// inlined is an error.
+    ambient_const_enums: FxHashSet<SymbolId>,
+    /// `const enum`s which are referenced other than by accessing a known member (e.g. `Foo[key]`),
+    /// so their declarations are kept as object literals.
+    referenced_const_enums: FxHashSet<SymbolId>,
+    /// `const enum`s exported from namespaces, keyed by the symbol of the outermost namespace
+    /// and the names of the nested namespaces and the enum, e.g. `Outer.Inner.Enum`.
+    nested_const_enums: FxHashMap<(SymbolId, std::vec::Vec<Atom<'a>>), SymbolId>,
+    /// Names already declared by top-level namespaces, e.g. `namespace Foo {}` -> `let Foo;`.
+    /// Top-level enums with one of these names are assigned to the existing binding.
+    namespace_names: FxHashSet<Atom<'a>>,
+    /// Enums exported from namespaces, and the parameter names of their namespaces' functions,
+    /// e.g. `_Foo`. They start from the existing `_Foo.Enum` object, as other blocks of
+    /// the namespace may have created it already.
+    namespace_exported_enums: FxHashMap<SymbolId, Atom<'a>>,
+    /// Symbol flags of the lowered enums' variables, which are applied in `exit_program`
+    /// as other transforms still need to know which bindings are enums until then.
+    lowered_enum_flags: std::vec::Vec<(SymbolId, SymbolFlags)>,
 }
 
 impl<'a> TypeScriptEnum<'a> {
-    pub fn new(ctx: Ctx<'a>) -> Self {
-        Self { ctx, enums: FxHashMap::default() }
+    pub fn new(options: Rc<TypeScriptOptions>, ctx: Ctx<'a>) -> Self {
+        Self {
+            ctx,
+            options,
+            enums: FxHashMap::default(),
+            const_enums: FxHashMap::default(),
+            ambient_const_enums: FxHashSet::default(),
+            referenced_const_enums: FxHashSet::default(),
+            nested_const_enums: FxHashMap::default(),
+            namespace_names: FxHashSet::default(),
+            namespace_exported_enums: FxHashMap::default(),
+            lowered_enum_flags: vec![],
+        }
+    }
+
+    pub fn set_namespace_names(&mut self, names: FxHashSet<Atom<'a>>) {
+        self.namespace_names = names;
+    }
+
+    pub fn set_namespace_exported_enums(&mut self, enums: FxHashMap<SymbolId, Atom<'a>>) {
+        self.namespace_exported_enums = enums;
+    }
+
+    fn get_symbol_id(ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        ctx.symbols().get_reference(reference_id).symbol_id()
+    }
+
+    /// Resolve the object of a member access to the symbol it refers to, and the identifier
+    /// the access starts with. `Outer.Inner` in `Outer.Inner.X` resolves to the `const enum`
+    /// exported from namespace `Outer` as `Inner`.
+    fn get_enum_object<'b>(
+        &self,
+        object: &'b Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(&'b IdentifierReference<'a>, SymbolId)> {
+        if let Expression::Identifier(ident) = object {
+            return Some((ident, Self::get_symbol_id(ident, ctx)?));
+        }
+        if self.nested_const_enums.is_empty() {
+            return None;
+        }
+        let mut names = vec![];
+        let mut object = object;
+        while let Expression::StaticMemberExpression(member) = object {
+            names.push(member.property.name.clone());
+            object = &member.object;
+        }
+        let Expression::Identifier(ident) = object else { return None };
+        names.reverse();
+        let namespace_symbol_id = Self::get_symbol_id(ident, ctx).or_else(|| {
+            // References to `declare namespace`s aren't resolved, as they have no runtime value
+            ctx.scopes().find_binding(ctx.current_scope_id(), &ident.name)
+        })?;
+        let symbol_id = self.nested_const_enums.get(&(namespace_symbol_id, names))?;
+        Some((ident, *symbol_id))
+    }
+
+    /// Whether the binding of the enum has been declared by an enum or namespace before it.
+    fn is_already_declared(&self, decl: &TSEnumDeclaration<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        self.enums.contains_key(&decl.id.symbol_id.get().unwrap())
+            || (ctx.scopes().get_flags(ctx.current_scope_id()).is_top()
+                && self.namespace_names.contains(&decl.id.name))
+    }
+
+    /// Whether the enum is merged with other enums or namespaces, before or after it,
+    /// which add to the enum object at runtime. Enums exported from namespaces may be merged
+    /// with enums in other blocks of the namespace.
+    fn is_merged(&self, decl: &TSEnumDeclaration<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let symbol_id = decl.id.symbol_id.get().unwrap();
+        self.is_already_declared(decl, ctx)
+            || self.namespace_exported_enums.contains_key(&symbol_id)
+            || !ctx.symbols().get_redeclarations(symbol_id).is_empty()
+            || ctx
+                .symbols()
+                .get_flags(symbol_id)
+                .intersects(SymbolFlags::NameSpaceModule | SymbolFlags::ValueModule)
     }
 }
 
 impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
+    /// Collect the member values of all `const enum`s up front, so that member accesses
+    /// can be inlined even when they appear before the enum declaration.
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.options.optimize_const_enums
+            && !ctx.symbols().flags.iter().any(|flags| flags.contains(SymbolFlags::ConstEnum))
+        {
+            return;
+        }
+
+        let mut collector = ConstEnumCollector {
+            r#enum: self,
+            ctx,
+            const_enums: FxHashMap::default(),
+            ambient_const_enums: FxHashSet::default(),
+            nested_const_enums: FxHashMap::default(),
+            namespace_path: None,
+            namespace_depth: 0,
+            is_exported: false,
+            is_ambient: false,
+        };
+        collector.visit_program(program);
+        let ConstEnumCollector { const_enums, ambient_const_enums, nested_const_enums, .. } =
+            collector;
+        self.const_enums = const_enums;
+        self.ambient_const_enums = ambient_const_enums;
+        self.nested_const_enums = nested_const_enums;
+
+        if !self.const_enums.is_empty() {
+            let mut finder = ConstEnumReferenceFinder {
+                const_enums: &self.const_enums,
+                ctx,
+                referenced: FxHashSet::default(),
+            };
+            finder.visit_program(program);
+            self.referenced_const_enums = finder.referenced;
+            // Namespaces assign their exported enums to properties, `_Outer.Inner = Inner;`
+            self.referenced_const_enums.extend(
+                self.nested_const_enums
+                    .values()
+                    .filter(|symbol_id| !self.ambient_const_enums.contains(symbol_id)),
+            );
+        }
+    }
+
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        for (symbol_id, flags) in self.lowered_enum_flags.drain(..) {
+            let symbol_flags = ctx.symbols_mut().get_flags_mut(symbol_id);
+            *symbol_flags = flags | (*symbol_flags & SymbolFlags::Export);
+            // Merged enums are declared by a single variable
+            ctx.symbols_mut().clear_redeclarations(symbol_id);
+        }
+    }
+
+    /// ```TypeScript
+    /// const enum Foo { X = 1 }
+    /// Foo.X;
+    /// ```
+    /// ```JavaScript
+    /// 1;
+    /// ```
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.const_enums.is_empty() {
+            return;
+        }
+
+        let span = expr.span();
+        let (ident, symbol_id, member_name) = match &*expr {
+            Expression::StaticMemberExpression(member) => {
+                let Some((ident, symbol_id)) = self.get_enum_object(&member.object, ctx) else {
+                    return;
+                };
+                (ident, symbol_id, Some(&member.property.name))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let Some((ident, symbol_id)) = self.get_enum_object(&member.object, ctx) else {
+                    return;
+                };
+                match &member.expression {
+                    Expression::StringLiteral(lit) => (ident, symbol_id, Some(&lit.value)),
+                    _ => (ident, symbol_id, None),
+                }
+            }
+            Expression::Identifier(ident) => {
+                // `Foo` in `Foo.X` is checked along with its member expression
+                if matches!(
+                    ctx.parent(),
+                    Ancestor::StaticMemberExpressionObject(_)
+                        | Ancestor::ComputedMemberExpressionObject(_)
+                ) {
+                    return;
+                }
+                let Some(symbol_id) = Self::get_symbol_id(ident, ctx) else { return };
+                (&**ident, symbol_id, None)
+            }
+            _ => return,
+        };
+        // Unknown members are left untouched
+        let Some(value) = member_name.and_then(|member_name| {
+            self.const_enums.get(&symbol_id).and_then(|members| members.get(member_name))
+        }) else {
+            // Ambient enums have no runtime object to fall back to
+            if self.ambient_const_enums.contains(&symbol_id)
+                && ctx.symbols().get_flags(symbol_id).contains(SymbolFlags::ConstEnum)
+            {
+                self.ctx.error(diagnostics::ambient_const_enum_access(span));
+            }
+            return;
+        };
+
+        let new_expr = match value.clone() {
+            ConstantValue::Number(v) => create_number(self.ctx.ast, span, v),
+            ConstantValue::String(str) => ctx.ast.expression_string_literal(span, str),
+        };
+        ctx.delete_reference_for_identifier(ident);
+        *expr = new_expr;
+    }
+
+    /// Declare the enums ahead of their IIFEs in [`EnumOutputStyle::Classic`].
+    /// ```TypeScript
+    /// export enum Foo { X }
+    /// ```
+    /// ```JavaScript
+    /// export var Foo;
+    /// (function(Foo) { ... })(Foo || (Foo = {}));
+    /// ```
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.enum_output_style != EnumOutputStyle::Classic
+            || !stmts.iter().any(|stmt| get_enum_declaration(stmt).is_some())
+        {
+            return;
+        }
+
+        let kind = if ctx.scopes().get_flags(ctx.current_scope_id()).is_top() {
+            VariableDeclarationKind::Var
+        } else {
+            VariableDeclarationKind::Let
+        };
+
+        let mut declared = FxHashSet::default();
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len() + 1);
+        for stmt in ctx.ast.move_vec(stmts) {
+            if let Some(decl) = get_enum_declaration(&stmt) {
+                let symbol_id = decl.id.symbol_id.get().unwrap();
+                // Re-opened enums are declared once, inlined `const enum`s aren't declared at all
+                if !self.const_enums.contains_key(&symbol_id)
+                    && !self.is_already_declared(decl, ctx)
+                    && declared.insert(symbol_id)
+                {
+                    let binding = ctx.ast.binding_pattern(
+                        ctx.ast.binding_pattern_kind_from_binding_identifier(decl.id.clone()),
+                        NONE,
+                        false,
+                    );
+                    let declarator = ctx.ast.variable_declarator(SPAN, kind, binding, None, false);
+                    let declaration =
+                        ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false);
+                    new_stmts.push(if matches!(stmt, Statement::ExportNamedDeclaration(_)) {
+                        Statement::ExportNamedDeclaration(
+                            ctx.ast.plain_export_named_declaration_declaration(SPAN, declaration),
+                        )
+                    } else {
+                        Statement::from(declaration)
+                    });
+                }
+            }
+            new_stmts.push(stmt);
+        }
+        *stmts = new_stmts;
+    }
+
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
@@ -73,10 +356,64 @@ impl<'a> TypeScriptEnum<'a> {
             return None;
         }
 
+        for member in &decl.members {
+            if is_numeric_member_name(&member.id) {
+                // `enum Foo { 1 = "a" }` is lowered to `Foo[1] = "a"`
+                self.ctx.error(diagnostics::enum_member_numeric_name(member.id.span()));
+            } else if self.get_member_name(member).is_none() {
+                // Members with computed names are skipped, the rest of the enum is still transformed
+                self.ctx.error(diagnostics::computed_enum_member_name(member.id.span()));
+            }
+        }
+
         let ast = ctx.ast;
 
         let is_export = export_span.is_some();
+        if is_export && decl.r#const && self.options.isolated_modules {
+            // Other modules can't inline the members without reading this file
+            self.ctx.error(diagnostics::exported_const_enum(decl.id.span));
+        }
         let is_not_top_scope = !ctx.scopes().get_flags(ctx.current_scope_id()).is_top();
+        // Merged enums and namespaces still need to add properties to the object
+        let freeze = self.options.freeze_enums && !self.is_merged(decl, ctx);
+
+        let symbol_id = decl.id.symbol_id.get().unwrap();
+        if let Some(members) = self.const_enums.get(&symbol_id) {
+            self.record_member_values(decl, members);
+            // Inlined const enums are removed along with other TypeScript-only declarations.
+            // Exported or otherwise referenced ones are kept as object literals.
+            if !is_export && !self.referenced_const_enums.contains(&symbol_id) {
+                return None;
+            }
+            let mut object = self.create_enum_object(decl, members, false, ctx);
+            if freeze {
+                object = Self::freeze_enum(object, ctx);
+            }
+            return Some(Self::create_enum_object_declaration(decl, object, export_span, ctx));
+        }
+
+        // var Foo = { X: 0, 0: "X" };
+        if let Some(members) = self.evaluate_object_literal_enum(decl, ctx) {
+            let mut object = self.create_enum_object(decl, &members, true, ctx);
+            if freeze {
+                object = Self::freeze_enum(object, ctx);
+            }
+            self.record_member_values(decl, &members);
+            self.enums.insert(symbol_id, members);
+            // There's no function for the enum's scope to belong to
+            if ctx.scopes().has_child_ids() {
+                let scope_id = decl.scope_id.get().unwrap();
+                let parent_scope_id = ctx.current_scope_id();
+                ctx.scopes_mut().get_child_ids_mut(parent_scope_id).retain(|id| *id != scope_id);
+            }
+            let flags = if is_export || is_not_top_scope {
+                SymbolFlags::BlockScopedVariable
+            } else {
+                SymbolFlags::FunctionScopedVariable
+            };
+            self.lowered_enum_flags.push((symbol_id, flags));
+            return Some(Self::create_enum_object_declaration(decl, object, export_span, ctx));
+        }
 
         let enum_name = decl.id.name.clone();
         let func_scope_id = decl.scope_id.get().unwrap();
@@ -97,7 +434,7 @@ impl<'a> TypeScriptEnum<'a> {
         let id = ast.binding_pattern(kind, NONE, false);
 
         // ((Foo) => {
-        let params = ast.formal_parameter(SPAN, ast.vec(), id, None, false, false);
+        let params = ast.formal_parameter(decl.id.span, ast.vec(), id, None, false, false);
         let params = ast.vec1(params);
         let params = ast.alloc_formal_parameters(
             SPAN,
@@ -107,13 +444,22 @@ impl<'a> TypeScriptEnum<'a> {
         );
 
         // Foo[Foo["X"] = 0] = "X";
-        let is_already_declared = self.enums.contains_key(&enum_name);
+        let is_already_declared = self.is_already_declared(decl, ctx);
 
-        let statements = self.transform_ts_enum_members(&mut decl.members, &ident, ctx);
+        let (statements, is_pure) =
+            self.transform_ts_enum_members(&mut decl.members, &ident, symbol_id, ctx);
+        self.record_member_values(decl, &self.enums[&symbol_id]);
+        // The enum's scope becomes the function's scope, where only the parameter is bound
+        for member in &decl.members {
+            if let Some(name) = self.get_member_name(member).filter(|name| *name != enum_name) {
+                ctx.scopes_mut().remove_binding(func_scope_id, &name.to_compact_str());
+            }
+        }
+        *ctx.scopes_mut().get_flags_mut(func_scope_id) |= ScopeFlags::Function;
         let body = ast.alloc_function_body(decl.span, ast.vec(), statements);
         let callee = Expression::FunctionExpression(ctx.alloc(Function {
             r#type: FunctionType::FunctionExpression,
-            span: SPAN,
+            span: decl.span,
             id: None,
             generator: false,
             r#async: false,
@@ -127,9 +473,47 @@ impl<'a> TypeScriptEnum<'a> {
         }));
 
         let var_symbol_id = decl.id.symbol_id.get().unwrap();
-        let arguments = if (is_export || is_not_top_scope) && !is_already_declared {
+        let is_classic = self.options.enum_output_style == EnumOutputStyle::Classic;
+        if !is_already_declared {
+            // `let` in nested scopes, so the binding doesn't leak out of blocks
+            let is_block_scoped = is_not_top_scope || (is_export && !is_classic);
+            let flags = if is_block_scoped {
+                SymbolFlags::BlockScopedVariable
+            } else {
+                SymbolFlags::FunctionScopedVariable
+            };
+            self.lowered_enum_flags.push((var_symbol_id, flags));
+        }
+        let arguments = if is_classic {
+            // }(Foo || (Foo = {}));
+            let left = ctx.create_bound_reference_id(
+                decl.id.span,
+                enum_name.clone(),
+                var_symbol_id,
+                ReferenceFlags::Read,
+            );
+            let left = ast.expression_from_identifier_reference(left);
+            // The value of the assignment is read as the argument
+            let target = ctx.create_bound_reference_id(
+                decl.id.span,
+                enum_name.clone(),
+                var_symbol_id,
+                ReferenceFlags::Read | ReferenceFlags::Write,
+            );
+            let target = ast.simple_assignment_target_from_identifier_reference(target);
+            let object_expr = self.create_initial_object(decl, ctx);
+            let right = ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                target.into(),
+                object_expr,
+            );
+            let right = ast.expression_parenthesized(SPAN, right);
+            let expression = ast.expression_logical(SPAN, left, LogicalOperator::Or, right);
+            ast.vec1(Argument::from(expression))
+        } else if (is_export || is_not_top_scope) && !is_already_declared {
             // }({});
-            let object_expr = ast.expression_object(SPAN, ast.vec(), None);
+            let object_expr = self.create_initial_object(decl, ctx);
             ast.vec1(Argument::from(object_expr))
         } else {
             // }(Foo || {});
@@ -146,7 +530,19 @@ impl<'a> TypeScriptEnum<'a> {
             ast.vec1(Argument::from(expression))
         };
 
-        let call_expression = ast.expression_call(SPAN, callee, NONE, arguments, false);
+        // The binding is declared in `enter_statements`
+        if is_classic {
+            let call_expression = ast.expression_call(decl.span, callee, NONE, arguments, false);
+            return Some(ast.statement_expression(decl.span, call_expression));
+        }
+
+        // var Foo = /* #__PURE__ */ function(Foo) { ... }(Foo || {});
+        let mut call_expression = ast.call_expression(decl.span, callee, NONE, arguments, false);
+        call_expression.pure = self.options.annotate_pure_enums && is_pure;
+        let mut call_expression = Expression::CallExpression(ast.alloc(call_expression));
+        if freeze {
+            call_expression = Self::freeze_enum(call_expression, ctx);
+        }
 
         if is_already_declared {
             let op = AssignmentOperator::Assign;
@@ -157,7 +553,7 @@ impl<'a> TypeScriptEnum<'a> {
                 ReferenceFlags::Write,
             );
             let left = ast.simple_assignment_target_from_identifier_reference(left);
-            let expr = ast.expression_assignment(SPAN, op, left.into(), call_expression);
+            let expr = ast.expression_assignment(decl.span, op, left.into(), call_expression);
             return Some(ast.statement_expression(decl.span, expr));
         }
 
@@ -171,7 +567,8 @@ impl<'a> TypeScriptEnum<'a> {
             let binding_pattern_kind =
                 ast.binding_pattern_kind_from_binding_identifier(binding_identifier);
             let binding = ast.binding_pattern(binding_pattern_kind, NONE, false);
-            let decl = ast.variable_declarator(SPAN, kind, binding, Some(call_expression), false);
+            let decl =
+                ast.variable_declarator(decl.span, kind, binding, Some(call_expression), false);
             ast.vec1(decl)
         };
         let variable_declaration = ast.declaration_variable(decl.span, kind, decls, false);
@@ -187,13 +584,195 @@ impl<'a> TypeScriptEnum<'a> {
         Some(stmt)
     }
 
-    #[allow(clippy::needless_pass_by_value)]
+    /// `{}`, or `_Foo.Enum || {}` for enums exported from namespaces
+    fn create_initial_object(
+        &self,
+        decl: &TSEnumDeclaration<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object_expr = ctx.ast.expression_object(SPAN, ctx.ast.vec(), None);
+        let symbol_id = decl.id.symbol_id.get().unwrap();
+        let Some(namespace_name) = self.namespace_exported_enums.get(&symbol_id) else {
+            return object_expr;
+        };
+        let namespace = ctx.ast.expression_identifier_reference(SPAN, namespace_name.clone());
+        let property = ctx.ast.identifier_name(SPAN, decl.id.name.clone());
+        let member = ctx.ast.member_expression_static(SPAN, namespace, property, false);
+        let member = Expression::from(member);
+        ctx.ast.expression_logical(SPAN, member, LogicalOperator::Or, object_expr)
+    }
+
+    /// Returns the members of an enum which can be emitted as an object literal with
+    /// `constant_enums_as_objects`, i.e. all of its members are constant and it is not merged
+    /// with other enums or namespaces, which add to the object at runtime. All-string enums
+    /// don't have reverse mappings, so they become plain objects: `{ Up: "up" }`.
+    fn evaluate_object_literal_enum(
+        &self,
+        decl: &TSEnumDeclaration<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<FxHashMap<Atom<'a>, ConstantValue>> {
+        if !self.options.constant_enums_as_objects
+            || self.options.enum_output_style != EnumOutputStyle::Compact
+            || self.is_merged(decl, ctx)
+            || decl.members.iter().any(|member| is_numeric_member_name(&member.id))
+        {
+            return None;
+        }
+        let members = self.evaluate_enum_members(&decl.members, ctx);
+        // Duplicate member names are assigned twice by the IIFE, so they are left to it as well
+        (members.len() == decl.members.len()).then_some(members)
+    }
+
+    /// ```TypeScript
+    /// const enum Foo { X, Y = "y" }
+    /// ```
+    /// ```JavaScript
+    /// { X: 0, Y: "y" }
+    /// ```
+    /// With `reverse_mappings`, numeric members are followed by their reverse mappings,
+    /// in the same order as the IIFE adds them: `{ X: 0, 0: "X", Y: "y" }`.
+    fn create_enum_object(
+        &self,
+        decl: &TSEnumDeclaration<'a>,
+        members: &FxHashMap<Atom<'a>, ConstantValue>,
+        reverse_mappings: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let create_property = |key, value| {
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            )
+        };
+
+        let mut properties = ctx.ast.vec_with_capacity(decl.members.len());
+        for member in &decl.members {
+            let Some(name) = self.get_member_name(member) else { continue };
+            let Some(value) = members.get(&name) else { continue };
+            let span = member.span;
+            let key = if matches!(member.id, TSEnumMemberName::StaticIdentifier(_)) {
+                ctx.ast.property_key_identifier_name(SPAN, name.clone())
+            } else {
+                PropertyKey::StringLiteral(
+                    ctx.ast.alloc(ctx.ast.string_literal(SPAN, name.clone())),
+                )
+            };
+            match value {
+                ConstantValue::Number(v) => {
+                    properties.push(create_property(key, create_number(self.ctx.ast, span, *v)));
+                    if reverse_mappings {
+                        // `0: "X"`, `"-1": "X"`
+                        let key = if !v.is_sign_negative() && v.is_finite() {
+                            ctx.ast.property_key_expression(create_number_literal(
+                                self.ctx.ast,
+                                member.id.span(),
+                                *v,
+                            ))
+                        } else {
+                            let key = ctx.ast.string_literal(member.id.span(), v.to_js_string());
+                            PropertyKey::StringLiteral(ctx.ast.alloc(key))
+                        };
+                        let value = ctx.ast.expression_string_literal(member.id.span(), name);
+                        properties.push(create_property(key, value));
+                    }
+                }
+                ConstantValue::String(str) => {
+                    let value = ctx.ast.expression_string_literal(span, str.clone());
+                    properties.push(create_property(key, value));
+                }
+            }
+        }
+        ctx.ast.expression_object(SPAN, properties, None)
+    }
+
+    /// Record the values of the enum's members, in declaration order, for
+    /// [`crate::TransformerReturn::enums`].
+    fn record_member_values(
+        &self,
+        decl: &TSEnumDeclaration<'a>,
+        members: &FxHashMap<Atom<'a>, ConstantValue>,
+    ) {
+        let members = decl
+            .members
+            .iter()
+            .filter_map(|member| self.get_member_name(member))
+            .map(|name| {
+                let value = match members.get(&name) {
+                    Some(ConstantValue::Number(v)) => EnumMemberValue::Number(*v),
+                    Some(ConstantValue::String(s)) => {
+                        EnumMemberValue::String(CompactStr::from(s.as_str()))
+                    }
+                    None => EnumMemberValue::Opaque,
+                };
+                (name.to_compact_str(), value)
+            })
+            .collect();
+        self.ctx.add_enum_member_values(EnumMemberValues {
+            enum_name: decl.id.name.to_compact_str(),
+            members,
+        });
+    }
+
+    /// `Object.freeze(value)`
+    fn freeze_enum(value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "Object");
+        let object =
+            ctx.create_reference_id(SPAN, Atom::from("Object"), symbol_id, ReferenceFlags::Read);
+        let object = ctx.ast.expression_from_identifier_reference(object);
+        let callee = ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, "freeze"),
+            false,
+        );
+        let arguments = ctx.ast.vec1(Argument::from(value));
+        ctx.ast.expression_call(SPAN, Expression::from(callee), NONE, arguments, false)
+    }
+
+    /// `var Foo = { ... };`, or `export let Foo = { ... };`
+    fn create_enum_object_declaration(
+        decl: &TSEnumDeclaration<'a>,
+        object: Expression<'a>,
+        export_span: Option<Span>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let ast = ctx.ast;
+        let is_not_top_scope = !ctx.scopes().get_flags(ctx.current_scope_id()).is_top();
+        let kind = if export_span.is_some() || is_not_top_scope {
+            VariableDeclarationKind::Let
+        } else {
+            VariableDeclarationKind::Var
+        };
+        let binding = ast.binding_pattern(
+            ast.binding_pattern_kind_from_binding_identifier(decl.id.clone()),
+            NONE,
+            false,
+        );
+        let declarator = ast.variable_declarator(decl.span, kind, binding, Some(object), false);
+        let declaration = ast.declaration_variable(decl.span, kind, ast.vec1(declarator), false);
+        match export_span {
+            Some(export_span) => Statement::ExportNamedDeclaration(
+                ast.plain_export_named_declaration_declaration(export_span, declaration),
+            ),
+            None => Statement::from(declaration),
+        }
+    }
+
+    /// Returns the statements of the IIFE body, and whether the members are free of side effects.
+    #[allow(clippy::needless_pass_by_value, clippy::float_cmp)]
     fn transform_ts_enum_members(
         &mut self,
         members: &mut Vec<'a, TSEnumMember<'a>>,
         param: &BindingIdentifier<'a>,
+        enum_symbol_id: SymbolId,
         ctx: &mut TraverseCtx<'a>,
-    ) -> Vec<'a, Statement<'a>> {
+    ) -> (Vec<'a, Statement<'a>>, bool) {
         let create_identifier_reference = |ctx: &mut TraverseCtx<'a>| {
             let ident = ctx.create_reference_id(
                 param.span,
@@ -208,65 +787,74 @@ impl<'a> TypeScriptEnum<'a> {
 
         let mut statements = ast.vec();
         let mut prev_constant_value = Some(ConstantValue::Number(-1.0));
-        let mut previous_enum_members = self.enums.entry(param.name.clone()).or_default().clone();
+        let mut previous_enum_members = self.enums.entry(enum_symbol_id).or_default().clone();
 
+        let mut non_constant_members = FxHashSet::default();
+        let mut is_prev_member_string = false;
         let mut prev_member_name: Option<Atom<'a>> = None;
 
         for member in members.iter_mut() {
-            let member_name: &Atom<'_> = match &member.id {
-                TSEnumMemberName::StaticIdentifier(id) => &id.name,
-                TSEnumMemberName::StaticStringLiteral(str)
-                | TSEnumMemberName::StringLiteral(str) => &str.value,
-                TSEnumMemberName::StaticTemplateLiteral(template)
-                | TSEnumMemberName::TemplateLiteral(template) => {
-                    &template.quasi().expect("Template enum members cannot have substitutions.")
-                }
-                // parse error, but better than a panic
-                TSEnumMemberName::StaticNumericLiteral(n) => &Atom::from(n.raw),
-                match_expression!(TSEnumMemberName) => {
-                    unreachable!()
-                }
-            };
+            let Some(member_name) = &self.get_member_name(member) else { continue };
+            // Checked before the initializer is folded or its references are renamed
+            let is_string = member.initializer.as_ref().is_some_and(|initializer| {
+                self.is_string_initializer(initializer, &previous_enum_members, ctx)
+            });
+
+            // Leave imprecise auto-incremented values to be computed at runtime, `1 + Foo["x"]`
+            if member.initializer.is_none()
+                && matches!(prev_constant_value, Some(ConstantValue::Number(v))
+                    if auto_increment(v).is_none())
+            {
+                prev_constant_value = None;
+            }
 
             let init = if let Some(initializer) = &mut member.initializer {
                 let constant_value =
-                    self.computed_constant_value(initializer, &previous_enum_members);
+                    self.computed_constant_value(initializer, &previous_enum_members, ctx);
 
+                let span = initializer.span();
                 // prev_constant_value = constant_value
                 let init = match constant_value {
                     None => {
                         prev_constant_value = None;
                         let mut new_initializer = ast.move_expression(initializer);
 
-                        // If the initializer is a binding identifier,
-                        // and it is not a binding in the current scope and parent scopes,
-                        // we need to rename it to the enum name. e.g. `d = c` to `d = A.c`
+                        // Resolve the references to the enum members, e.g. `d = c` to `d = A.c`
                         // same behavior in https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L145-L150
-                        let has_binding = matches!(
-                            &new_initializer,
-                            Expression::Identifier(ident) if ctx.scopes().has_binding(ctx.current_scope_id(), &ident.name)
-                        );
-                        if !has_binding {
-                            IdentifierReferenceRename::new(
-                                param.name.clone(),
-                                previous_enum_members.clone(),
-                                ctx,
-                            )
-                            .visit_expression(&mut new_initializer);
-                        }
+                        IdentifierReferenceRename::new(
+                            param,
+                            &previous_enum_members,
+                            &non_constant_members,
+                            self.options.optimize_const_enums,
+                            ctx,
+                        )
+                        .visit_expression(&mut new_initializer);
+                        non_constant_members.insert(member_name.clone());
 
                         new_initializer
                     }
                     Some(constant_value) => {
-                        previous_enum_members.insert(member_name.clone(), constant_value.clone());
+                        // The initializer is replaced with its value, along with its references
+                        let mut collector = ReferenceCollector::default();
+                        collector.visit_expression(initializer);
+                        for (reference_id, name) in collector.references {
+                            ctx.delete_reference(reference_id, &name);
+                        }
+                        self.add_constant_member(
+                            enum_symbol_id,
+                            member_name,
+                            &constant_value,
+                            &mut previous_enum_members,
+                        );
                         match constant_value {
                             ConstantValue::Number(v) => {
                                 prev_constant_value = Some(ConstantValue::Number(v));
-                                self.get_initializer_expr(v)
+                                create_number(self.ctx.ast, span, v)
                             }
                             ConstantValue::String(str) => {
-                                prev_constant_value = None;
-                                ast.expression_string_literal(SPAN, str)
+                                let expr = ast.expression_string_literal(span, str.clone());
+                                prev_constant_value = Some(ConstantValue::String(str));
+                                expr
                             }
                         }
                     }
@@ -278,125 +866,216 @@ impl<'a> TypeScriptEnum<'a> {
                     ConstantValue::Number(value) => {
                         let value = value + 1.0;
                         let constant_value = ConstantValue::Number(value);
-                        prev_constant_value = Some(constant_value.clone());
-                        previous_enum_members.insert(member_name.clone(), constant_value);
-                        self.get_initializer_expr(value)
+                        self.add_constant_member(
+                            enum_symbol_id,
+                            member_name,
+                            &constant_value,
+                            &mut previous_enum_members,
+                        );
+                        prev_constant_value = Some(constant_value);
+                        create_number(self.ctx.ast, member.span, value)
+                    }
+                    // `enum Foo { A = "a", B }` is an error in TypeScript,
+                    // as there's no way to auto-increment a string member
+                    ConstantValue::String(_) => {
+                        self.ctx.error(diagnostics::enum_member_must_have_initializer(
+                            member.id.span(),
+                        ));
+                        prev_constant_value = None;
+                        ast.void_0()
                     }
-                    ConstantValue::String(_) => unreachable!(),
                 }
+            } else if is_prev_member_string {
+                // `enum Foo { A = `${a}`, B }` is an error in TypeScript
+                self.ctx.error(diagnostics::enum_member_must_have_initializer(member.id.span()));
+                ast.void_0()
             } else if let Some(prev_member_name) = prev_member_name {
+                non_constant_members.insert(member_name.clone());
                 let self_ref = {
                     let obj = create_identifier_reference(ctx);
-                    let expr = ctx.ast.expression_string_literal(SPAN, prev_member_name);
-                    ast.member_expression_computed(SPAN, obj, expr, false).into()
+                    let expr = ctx.ast.expression_string_literal(member.span, prev_member_name);
+                    ast.member_expression_computed(member.span, obj, expr, false).into()
                 };
 
                 // 1 + Foo["x"]
-                let one = self.get_number_literal_expression(1.0);
-                ast.expression_binary(SPAN, one, BinaryOperator::Addition, self_ref)
+                let one = create_number_literal(self.ctx.ast, member.span, 1.0);
+                ast.expression_binary(member.span, one, BinaryOperator::Addition, self_ref)
             } else {
-                self.get_number_literal_expression(0.0)
+                create_number_literal(self.ctx.ast, member.span, 0.0)
             };
 
-            let is_str = init.is_string_literal();
+            // Strings don't have reverse mappings
+            let is_str = is_string || is_syntactically_string(&init);
 
             // Foo["x"] = init
+            let id_span = member.id.span();
             let member_expr = {
                 let obj = create_identifier_reference(ctx);
-                let expr = ast.expression_string_literal(SPAN, member_name);
+                let expr = match &member.id {
+                    // Foo[1] = init
+                    TSEnumMemberName::StaticNumericLiteral(n)
+                    | TSEnumMemberName::NumericLiteral(n) => {
+                        create_number_literal(self.ctx.ast, id_span, n.value)
+                    }
+                    _ => ast.expression_string_literal(id_span, member_name),
+                };
 
-                ast.member_expression_computed(SPAN, obj, expr, false)
+                ast.member_expression_computed(id_span, obj, expr, false)
             };
             let left = ast.simple_assignment_target_member_expression(member_expr);
-            let mut expr =
-                ast.expression_assignment(SPAN, AssignmentOperator::Assign, left.into(), init);
+            let mut expr = ast.expression_assignment(
+                member.span,
+                AssignmentOperator::Assign,
+                left.into(),
+                init,
+            );
 
             // Foo[Foo["x"] = init] = "x"
             if !is_str {
                 let member_expr = {
                     let obj = create_identifier_reference(ctx);
-                    ast.member_expression_computed(SPAN, obj, expr, false)
+                    ast.member_expression_computed(member.span, obj, expr, false)
                 };
                 let left = ast.simple_assignment_target_member_expression(member_expr);
-                let right = ast.expression_string_literal(SPAN, member_name);
-                expr =
-                    ast.expression_assignment(SPAN, AssignmentOperator::Assign, left.into(), right);
+                let right = ast.expression_string_literal(id_span, member_name);
+                expr = ast.expression_assignment(
+                    member.span,
+                    AssignmentOperator::Assign,
+                    left.into(),
+                    right,
+                );
             }
 
             prev_member_name = Some(member_name.clone());
+            is_prev_member_string = is_string;
             statements.push(ast.statement_expression(member.span, expr));
         }
 
-        self.enums.insert(param.name.clone(), previous_enum_members.clone());
+        self.enums.insert(enum_symbol_id, previous_enum_members.clone());
 
-        let enum_ref = create_identifier_reference(ctx);
-        // return Foo;
-        let return_stmt = ast.statement_return(SPAN, Some(enum_ref));
-        statements.push(return_stmt);
+        // The classic IIFE assigns the object to the binding in its argument instead
+        if self.options.enum_output_style == EnumOutputStyle::Compact {
+            let enum_ref = create_identifier_reference(ctx);
+            // return Foo;
+            let return_stmt = ast.statement_return(SPAN, Some(enum_ref));
+            statements.push(return_stmt);
+        }
 
-        statements
+        // Members which are not evaluated at compile time may have side effects, e.g. `A = foo()`
+        let is_pure = non_constant_members.is_empty();
+        (statements, is_pure)
     }
 
-    fn get_number_literal_expression(&self, value: f64) -> Expression<'a> {
-        self.ctx.ast.expression_numeric_literal(SPAN, value, value.to_string(), NumberBase::Decimal)
+    /// Record a constant member, so it can be referenced by later members
+    /// either by name (`A`) or through the enum (`Foo.A`, `Foo["A"]`).
+    fn add_constant_member(
+        &mut self,
+        enum_symbol_id: SymbolId,
+        member_name: &Atom<'a>,
+        value: &ConstantValue,
+        previous_enum_members: &mut FxHashMap<Atom<'a>, ConstantValue>,
+    ) {
+        previous_enum_members.insert(member_name.clone(), value.clone());
+        self.enums.entry(enum_symbol_id).or_default().insert(member_name.clone(), value.clone());
     }
 
-    fn get_initializer_expr(&self, value: f64) -> Expression<'a> {
-        let is_negative = value < 0.0;
+    /// Get the name of an enum member.
+    /// Returns `None` for computed names, e.g. `[foo]` or `` `a${b}` ``, which are invalid.
+    fn get_member_name(&self, member: &TSEnumMember<'a>) -> Option<Atom<'a>> {
+        match &member.id {
+            TSEnumMemberName::StaticIdentifier(id) => Some(id.name.clone()),
+            TSEnumMemberName::StaticStringLiteral(str) | TSEnumMemberName::StringLiteral(str) => {
+                Some(str.value.clone())
+            }
+            TSEnumMemberName::StaticTemplateLiteral(template)
+            | TSEnumMemberName::TemplateLiteral(template) => template.quasi(),
+            // parse error, but better than a panic.
+            // `0x10` is the same key as `"16"`
+            TSEnumMemberName::StaticNumericLiteral(n) | TSEnumMemberName::NumericLiteral(n) => {
+                Some(self.ctx.ast.atom(&n.value.to_js_string()))
+            }
+            _ => None,
+        }
+    }
 
-        // Infinity
-        let expr = if value.is_infinite() {
-            self.ctx.ast.expression_identifier_reference(SPAN, "Infinity")
-        } else {
-            let value = if is_negative { -value } else { value };
-            self.get_number_literal_expression(value)
-        };
+    /// Evaluate the members of an enum without transforming it.
+    /// Members whose value cannot be computed at compile time are omitted.
+    fn evaluate_enum_members(
+        &self,
+        members: &[TSEnumMember<'a>],
+        ctx: &TraverseCtx<'a>,
+    ) -> FxHashMap<Atom<'a>, ConstantValue> {
+        let mut evaluated = FxHashMap::default();
+        let mut prev_constant_value = Some(ConstantValue::Number(-1.0));
 
-        if is_negative {
-            self.ctx.ast.expression_unary(SPAN, UnaryOperator::UnaryNegation, expr)
-        } else {
-            expr
+        for member in members {
+            let Some(member_name) = self.get_member_name(member) else { continue };
+            let constant_value = if let Some(initializer) = &member.initializer {
+                self.computed_constant_value(initializer, &evaluated, ctx)
+            } else if let Some(ConstantValue::Number(value)) = prev_constant_value {
+                auto_increment(value).map(ConstantValue::Number)
+            } else {
+                None
+            };
+
+            if let Some(value) = &constant_value {
+                evaluated.insert(member_name, value.clone());
+            }
+            prev_constant_value = constant_value;
         }
+
+        evaluated
     }
 }
 
-#[derive(Debug, Clone)]
-enum ConstantValue {
+/// The members of an enum declaration, along with their values where they are known at
+/// compile time, e.g. for inlining enum members across files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumMemberValues {
+    pub enum_name: CompactStr,
+    /// In declaration order. Members with computed names are skipped.
+    pub members: std::vec::Vec<(CompactStr, EnumMemberValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumMemberValue {
     Number(f64),
-    String(String),
+    String(CompactStr),
+    /// The value is only known at runtime, e.g. `A = foo()`.
+    Opaque,
 }
 
 impl<'a> TypeScriptEnum<'a> {
-    /// Evaluate the expression to a constant value.
-    /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
+    /// Evaluate the expression to a constant value, with references to the previous members
+    /// of the enum and the members of enums transformed before it.
     fn computed_constant_value(
         &self,
         expr: &Expression<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
+        ctx: &TraverseCtx<'a>,
     ) -> Option<ConstantValue> {
-        self.evaluate(expr, prev_members)
+        eval_ts_const_expr(expr, &|expr| self.evaluate_ref(expr, prev_members, ctx))
     }
 
     fn evaluate_ref(
         &self,
         expr: &Expression<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
+        ctx: &TraverseCtx<'a>,
     ) -> Option<ConstantValue> {
         match expr {
             match_member_expression!(Expression) => {
                 let expr = expr.to_member_expression();
                 let Expression::Identifier(ident) = expr.object() else { return None };
-                let members = self.enums.get(&ident.name)?;
+                // Only enums transformed before this one are known,
+                // imported or later declared enums are left to runtime
+                let reference_id = ident.reference_id.get()?;
+                let symbol_id = ctx.symbols().get_reference(reference_id).symbol_id()?;
+                let members = self.enums.get(&symbol_id)?;
                 let property = expr.static_property_name()?;
                 return members.get(property).cloned();
             }
             Expression::Identifier(ident) => {
-                if ident.name == "Infinity" {
-                    return Some(ConstantValue::Number(f64::INFINITY));
-                } else if ident.name == "NaN" {
-                    return Some(ConstantValue::Number(f64::NAN));
-                }
-
                 if let Some(value) = prev_members.get(&ident.name) {
                     return Some(value.clone());
                 }
@@ -411,129 +1090,233 @@ impl<'a> TypeScriptEnum<'a> {
             _ => None,
         }
     }
+}
 
-    fn evaluate(
+impl<'a> TypeScriptEnum<'a> {
+    /// Check if the initializer of a member is a string, either syntactically (`"a" + b`) or by
+    /// concatenating a string member, e.g. `A + b` or `Foo.A + b` where `A = "a"`.
+    fn is_string_initializer(
         &self,
         expr: &Expression<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
-    ) -> Option<ConstantValue> {
-        match expr {
-            Expression::Identifier(_)
-            | Expression::ComputedMemberExpression(_)
-            | Expression::StaticMemberExpression(_)
-            | Expression::PrivateFieldExpression(_) => self.evaluate_ref(expr, prev_members),
-            Expression::BinaryExpression(expr) => self.eval_binary_expression(expr, prev_members),
-            Expression::UnaryExpression(expr) => self.eval_unary_expression(expr, prev_members),
-            Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
-            Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
-            Expression::TemplateLiteral(lit) => {
-                let mut value = String::new();
-                for part in &lit.quasis {
-                    value.push_str(&part.value.raw);
-                }
-                Some(ConstantValue::String(value))
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        match expr.get_inner_expression() {
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+            Expression::BinaryExpression(expr) => {
+                expr.operator == BinaryOperator::Addition
+                    && (self.is_string_initializer(&expr.left, prev_members, ctx)
+                        || self.is_string_initializer(&expr.right, prev_members, ctx))
             }
-            Expression::ParenthesizedExpression(expr) => {
-                self.evaluate(&expr.expression, prev_members)
+            expr => {
+                matches!(self.evaluate_ref(expr, prev_members, ctx), Some(ConstantValue::String(_)))
             }
-            _ => None,
         }
     }
+}
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
-    fn eval_binary_expression(
-        &self,
-        expr: &BinaryExpression<'a>,
-        prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
-    ) -> Option<ConstantValue> {
-        let left = self.evaluate(&expr.left, prev_members)?;
-        let right = self.evaluate(&expr.right, prev_members)?;
+/// Check if the expression is always evaluated to a string, e.g. `"a" + b` or `` `${a}` ``.
+/// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L86-L100)
+fn is_syntactically_string(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::BinaryExpression(expr) => {
+            expr.operator == BinaryOperator::Addition
+                && (is_syntactically_string(&expr.left) || is_syntactically_string(&expr.right))
+        }
+        _ => false,
+    }
+}
 
-        if matches!(expr.operator, BinaryOperator::Addition)
-            && (matches!(left, ConstantValue::String(_))
-                || matches!(right, ConstantValue::String(_)))
-        {
-            let left_string = match left {
-                ConstantValue::String(str) => str,
-                ConstantValue::Number(v) => v.to_js_string(),
-            };
+/// The value of a member without an initializer after a member with the value `prev`.
+/// Returns `None` when the value can't be represented exactly beyond the safe integers,
+/// e.g. after `2 ** 53`, so it is computed at runtime instead.
+fn auto_increment(prev: f64) -> Option<f64> {
+    let max = 2f64.powi(53);
+    (!prev.is_finite() || (-max..max).contains(&prev)).then_some(prev + 1.0)
+}
 
-            let right_string = match right {
-                ConstantValue::String(str) => str,
-                ConstantValue::Number(v) => v.to_js_string(),
-            };
+/// `enum Foo {}` or `export enum Foo {}`
+fn get_enum_declaration<'b, 'a>(stmt: &'b Statement<'a>) -> Option<&'b TSEnumDeclaration<'a>> {
+    match stmt {
+        Statement::TSEnumDeclaration(decl) => Some(decl),
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::TSEnumDeclaration(decl)) => Some(decl),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
-            return Some(ConstantValue::String(format!("{left_string}{right_string}")));
-        }
+fn is_numeric_member_name(name: &TSEnumMemberName) -> bool {
+    matches!(name, TSEnumMemberName::StaticNumericLiteral(_) | TSEnumMemberName::NumericLiteral(_))
+}
 
-        let left = match left {
-            ConstantValue::Number(v) => v,
-            ConstantValue::String(_) => return None,
-        };
+/// Collect the member values of every `const enum` declaration in the program
+struct ConstEnumCollector<'a, 'b> {
+    r#enum: &'b TypeScriptEnum<'a>,
+    ctx: &'b TraverseCtx<'a>,
+    const_enums: FxHashMap<SymbolId, FxHashMap<Atom<'a>, ConstantValue>>,
+    ambient_const_enums: FxHashSet<SymbolId>,
+    nested_const_enums: FxHashMap<(SymbolId, std::vec::Vec<Atom<'a>>), SymbolId>,
+    /// The symbol of the outermost namespace being visited, and the names of the namespaces
+    /// nested in it, while they are all exported, i.e. accessible as `Outer.Inner`.
+    namespace_path: Option<(SymbolId, std::vec::Vec<Atom<'a>>)>,
+    namespace_depth: usize,
+    /// Whether the declaration being visited is exported, `export const enum Foo {}`
+    is_exported: bool,
+    /// Whether the declaration being visited is in a `declare namespace`
+    is_ambient: bool,
+}
 
-        let right = match right {
-            ConstantValue::Number(v) => v,
-            ConstantValue::String(_) => return None,
-        };
+impl<'a, 'b> Visit<'a> for ConstEnumCollector<'a, 'b> {
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        self.is_exported = matches!(
+            decl.declaration,
+            Some(Declaration::TSEnumDeclaration(_) | Declaration::TSModuleDeclaration(_))
+        );
+        walk::walk_export_named_declaration(self, decl);
+        self.is_exported = false;
+    }
+
+    fn visit_ts_module_declaration(&mut self, decl: &TSModuleDeclaration<'a>) {
+        let is_exported = mem::take(&mut self.is_exported);
+        let TSModuleDeclarationName::Identifier(id) = &decl.id else { return };
+        let Some(body) = &decl.body else { return };
 
-        match expr.operator {
-            BinaryOperator::ShiftRight => Some(ConstantValue::Number(f64::from(
-                left.to_js_int_32().wrapping_shr(right.to_js_int_32() as u32),
-            ))),
-            BinaryOperator::ShiftRightZeroFill => Some(ConstantValue::Number(f64::from(
-                (left.to_js_int_32() as u32).wrapping_shr(right.to_js_int_32() as u32),
-            ))),
-            BinaryOperator::ShiftLeft => Some(ConstantValue::Number(f64::from(
-                left.to_js_int_32().wrapping_shl(right.to_js_int_32() as u32),
-            ))),
-            BinaryOperator::BitwiseXOR => {
-                Some(ConstantValue::Number(f64::from(left.to_js_int_32() ^ right.to_js_int_32())))
+        let namespace_path = if self.namespace_depth == 0 {
+            let scope_id = decl.scope_id.get().unwrap();
+            let scopes = self.ctx.scopes();
+            scopes
+                .get_parent_id(scope_id)
+                .and_then(|parent_scope_id| scopes.get_binding(parent_scope_id, &id.name))
+                .map(|symbol_id| (symbol_id, vec![]))
+        } else if is_exported || self.is_ambient {
+            self.namespace_path.clone().map(|(symbol_id, mut names)| {
+                names.push(id.name.clone());
+                (symbol_id, names)
+            })
+        } else {
+            None
+        };
+        let parent_namespace_path = mem::replace(&mut self.namespace_path, namespace_path);
+        let is_ambient = self.is_ambient;
+        self.is_ambient |= decl.declare;
+        self.namespace_depth += 1;
+        match body {
+            // `namespace Outer.Inner {}`
+            TSModuleDeclarationBody::TSModuleDeclaration(decl) => {
+                self.is_exported = true;
+                self.visit_ts_module_declaration(decl);
             }
-            BinaryOperator::BitwiseOR => {
-                Some(ConstantValue::Number(f64::from(left.to_js_int_32() | right.to_js_int_32())))
+            TSModuleDeclarationBody::TSModuleBlock(block) => self.visit_ts_module_block(block),
+        }
+        self.namespace_depth -= 1;
+        self.is_ambient = is_ambient;
+        self.namespace_path = parent_namespace_path;
+    }
+
+    fn visit_ts_enum_declaration(&mut self, decl: &TSEnumDeclaration<'a>) {
+        let is_exported = mem::take(&mut self.is_exported);
+        if !decl.r#const {
+            return;
+        }
+        // Ambient enums have no runtime object, so they are always inlined.
+        // Preserved enums exist at runtime, so their members are accessed through the object.
+        let is_ambient = decl.declare || self.is_ambient;
+        let options = &self.r#enum.options;
+        if !is_ambient && (!options.optimize_const_enums || options.preserve_const_enums) {
+            return;
+        }
+        let Some(symbol_id) = decl.id.symbol_id.get() else { return };
+        let members = self.r#enum.evaluate_enum_members(&decl.members, self.ctx);
+        if is_ambient {
+            // Members which can't be evaluated are reported where they are accessed
+            self.ambient_const_enums.insert(symbol_id);
+        } else if members.len() != decl.members.len() {
+            // Enums with non-constant members can't be inlined, so they are transformed as usual
+            for member in &decl.members {
+                let Some(initializer) = &member.initializer else { continue };
+                if self
+                    .r#enum
+                    .get_member_name(member)
+                    .is_some_and(|name| !members.contains_key(&name))
+                {
+                    self.r#enum
+                        .ctx
+                        .error(diagnostics::const_enum_member_not_constant(initializer.span()));
+                }
             }
-            BinaryOperator::BitwiseAnd => {
-                Some(ConstantValue::Number(f64::from(left.to_js_int_32() & right.to_js_int_32())))
+            return;
+        }
+        self.const_enums.insert(symbol_id, members);
+        // Declarations in `declare namespace`s are exported implicitly
+        if (is_exported || self.is_ambient) && self.namespace_depth > 0 {
+            if let Some((namespace_symbol_id, mut names)) = self.namespace_path.clone() {
+                names.push(decl.id.name.clone());
+                self.nested_const_enums.insert((namespace_symbol_id, names), symbol_id);
             }
-            BinaryOperator::Multiplication => Some(ConstantValue::Number(left * right)),
-            BinaryOperator::Division => Some(ConstantValue::Number(left / right)),
-            BinaryOperator::Addition => Some(ConstantValue::Number(left + right)),
-            BinaryOperator::Subtraction => Some(ConstantValue::Number(left - right)),
-            BinaryOperator::Remainder => Some(ConstantValue::Number(left % right)),
-            BinaryOperator::Exponential => Some(ConstantValue::Number(left.powf(right))),
-            _ => None,
         }
     }
+}
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
-    fn eval_unary_expression(
-        &self,
-        expr: &UnaryExpression<'a>,
-        prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
-    ) -> Option<ConstantValue> {
-        let value = self.evaluate(&expr.argument, prev_members)?;
-
-        let value = match value {
-            ConstantValue::Number(value) => value,
-            ConstantValue::String(_) => {
-                let value = if expr.operator == UnaryOperator::UnaryNegation {
-                    ConstantValue::Number(f64::NAN)
-                } else if expr.operator == UnaryOperator::BitwiseNot {
-                    ConstantValue::Number(-1.0)
-                } else {
-                    value
-                };
-                return Some(value);
-            }
-        };
+/// Find the `const enum`s which are referenced other than by accessing one of their members
+/// with a static key, e.g. `Foo[key]` or `export { Foo }`.
+struct ConstEnumReferenceFinder<'a, 'b> {
+    const_enums: &'b FxHashMap<SymbolId, FxHashMap<Atom<'a>, ConstantValue>>,
+    ctx: &'b TraverseCtx<'a>,
+    referenced: FxHashSet<SymbolId>,
+}
+
+impl<'a, 'b> ConstEnumReferenceFinder<'a, 'b> {
+    fn get_const_enum(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = self.ctx.symbols().get_reference(reference_id).symbol_id()?;
+        self.const_enums.contains_key(&symbol_id).then_some(symbol_id)
+    }
+}
 
-        match expr.operator {
-            UnaryOperator::UnaryPlus => Some(ConstantValue::Number(value)),
-            UnaryOperator::UnaryNegation => Some(ConstantValue::Number(-value)),
-            UnaryOperator::BitwiseNot => {
-                Some(ConstantValue::Number(f64::from(!value.to_js_int_32())))
+impl<'a, 'b> Visit<'a> for ConstEnumReferenceFinder<'a, 'b> {
+    fn visit_member_expression(&mut self, expr: &MemberExpression<'a>) {
+        let member_name = match expr {
+            MemberExpression::StaticMemberExpression(member) => Some(&member.property.name),
+            MemberExpression::ComputedMemberExpression(member) => match &member.expression {
+                Expression::StringLiteral(lit) => Some(&lit.value),
+                _ => None,
+            },
+            MemberExpression::PrivateFieldExpression(_) => None,
+        };
+        if let (Expression::Identifier(ident), Some(member_name)) = (expr.object(), member_name) {
+            if let Some(symbol_id) = self.get_const_enum(ident) {
+                if self.const_enums[&symbol_id].contains_key(member_name) {
+                    // Inlined, `Foo.X` -> `0`
+                    return;
+                }
             }
-            _ => None,
+        }
+        walk::walk_member_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.get_const_enum(ident) {
+            self.referenced.insert(symbol_id);
+        }
+    }
+
+    // Type references don't need the enum at runtime
+    fn visit_ts_type(&mut self, _ty: &TSType<'a>) {}
+}
+
+/// Collect the references in an enum member initializer which is replaced by its value
+#[derive(Default)]
+struct ReferenceCollector<'a> {
+    references: std::vec::Vec<(ReferenceId, Atom<'a>)>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(reference_id) = ident.reference_id.get() {
+            self.references.push((reference_id, ident.name.clone()));
         }
     }
 }
@@ -543,7 +1326,9 @@ impl<'a> TypeScriptEnum<'a> {
 /// enum A {
 ///    a = 1,
 ///    b = a.toString(),
+///    c = foo(),
 ///    d = c,
+///    e = f,
 /// }
 /// ```
 /// will be transformed to
@@ -551,22 +1336,80 @@ impl<'a> TypeScriptEnum<'a> {
 /// enum A {
 ///   a = 1,
 ///   b = A.a.toString(),
-///   d = A.c,
+///   c = foo(),
+///   d = A["c"],
+///   e = A.f,
 /// }
 /// ```
+/// References to previous members are resolved to the members even if a binding with the same
+/// name exists outside the enum. Constant members accessed through the enum are replaced with
+/// their values, e.g. `A["a"] + c` -> `1 + A["c"]`. With `optimize_const_enums`, bare references
+/// to them are replaced as well, e.g. `b = 1..toString()`.
 struct IdentifierReferenceRename<'a, 'b> {
     enum_name: Atom<'a>,
-    ctx: &'b TraverseCtx<'a>,
-    previous_enum_members: FxHashMap<Atom<'a>, ConstantValue>,
+    /// The parameter of the IIFE which the enum is referred to by
+    enum_symbol_id: Option<SymbolId>,
+    ctx: &'b mut TraverseCtx<'a>,
+    previous_enum_members: &'b FxHashMap<Atom<'a>, ConstantValue>,
+    /// Previous members whose values are not known at compile time
+    non_constant_members: &'b FxHashSet<Atom<'a>>,
+    /// Replace bare references to constant members with their values
+    inline_constants: bool,
 }
 
 impl<'a, 'b> IdentifierReferenceRename<'a, 'b> {
     fn new(
-        enum_name: Atom<'a>,
-        previous_enum_members: FxHashMap<Atom<'a>, ConstantValue>,
-        ctx: &'b TraverseCtx<'a>,
+        param: &BindingIdentifier<'a>,
+        previous_enum_members: &'b FxHashMap<Atom<'a>, ConstantValue>,
+        non_constant_members: &'b FxHashSet<Atom<'a>>,
+        inline_constants: bool,
+        ctx: &'b mut TraverseCtx<'a>,
     ) -> Self {
-        IdentifierReferenceRename { enum_name, ctx, previous_enum_members }
+        IdentifierReferenceRename {
+            enum_name: param.name.clone(),
+            enum_symbol_id: param.symbol_id.get(),
+            ctx,
+            previous_enum_members,
+            non_constant_members,
+            inline_constants,
+        }
+    }
+
+    /// `Foo`, the parameter of the IIFE
+    fn create_enum_reference(&mut self) -> Expression<'a> {
+        let ident = self.ctx.create_reference_id(
+            SPAN,
+            self.enum_name.clone(),
+            self.enum_symbol_id,
+            ReferenceFlags::Read,
+        );
+        self.ctx.ast.expression_from_identifier_reference(ident)
+    }
+
+    fn is_previous_member(&self, name: &Atom<'a>) -> bool {
+        self.previous_enum_members.contains_key(name) || self.non_constant_members.contains(name)
+    }
+
+    /// Whether the identifier refers to a binding other than a member of the enum, either outside
+    /// the enum or in a function in the initializer, e.g. `(n) => n`.
+    fn is_bound(&self, ident: &IdentifierReference<'a>) -> bool {
+        let symbols = self.ctx.symbols();
+        ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| symbols.get_reference(reference_id).symbol_id())
+            .is_some_and(|symbol_id| {
+                !symbols.get_flags(symbol_id).contains(SymbolFlags::EnumMember)
+            })
+            || self.ctx.scopes().find_binding(self.ctx.current_scope_id(), &ident.name).is_some()
+    }
+
+    fn get_constant_expr(&self, span: Span, name: &str) -> Option<Expression<'a>> {
+        let expr = match self.previous_enum_members.get(name)?.clone() {
+            ConstantValue::Number(v) => create_number(self.ctx.ast, span, v),
+            ConstantValue::String(str) => self.ctx.ast.expression_string_literal(span, str),
+        };
+        Some(expr)
     }
 }
 
@@ -574,38 +1417,196 @@ impl<'a, 'b> VisitMut<'a> for IdentifierReferenceRename<'a, 'b> {
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
         let new_expr = match expr {
             match_member_expression!(Expression) => {
-                // handle a.toString() -> A.a.toString()
-                let expr = expr.to_member_expression();
-                if let Expression::Identifier(ident) = expr.object() {
-                    if !self.previous_enum_members.contains_key(&ident.name) {
+                let member_expr = expr.to_member_expression_mut();
+                match member_expr.object() {
+                    Expression::Identifier(ident)
+                        if ident.name == self.enum_name
+                            && !self.is_previous_member(&ident.name) =>
+                    {
+                        // `A["not ident"]` -> `1`
+                        member_expr
+                            .static_property_name()
+                            .and_then(|name| self.get_constant_expr(member_expr.span(), name))
+                    }
+                    // handle a.toString() -> 1..toString()
+                    Expression::Identifier(ident) if self.is_previous_member(&ident.name) => None,
+                    // `foo.a` and `foo[a]`, only the computed property may refer to a member
+                    Expression::Identifier(_) => {
+                        if let MemberExpression::ComputedMemberExpression(member_expr) = member_expr
+                        {
+                            self.visit_expression(&mut member_expr.expression);
+                        }
                         return;
                     }
-                };
-                None
+                    // `[1, 2].map((n) => n * a)`
+                    _ => None,
+                }
             }
             Expression::Identifier(ident) => {
-                // If the identifier is binding in current/parent scopes,
-                // and it is not a member of the enum,
-                // we don't need to rename it.
-                // `var c = 1; enum A { a = c }` -> `var c = 1; enum A { a = c }
-                if !self.previous_enum_members.contains_key(&ident.name)
-                    && self.ctx.scopes().has_binding(self.ctx.current_scope_id(), &ident.name)
-                {
+                let constant_expr = if self.inline_constants {
+                    self.get_constant_expr(ident.span, &ident.name)
+                } else {
+                    None
+                };
+                if let Some(value) = constant_expr {
+                    Some(value)
+                } else if self.previous_enum_members.contains_key(&ident.name) {
+                    // enum_name.identifier
+                    let object = self.create_enum_reference();
+                    let property = self.ctx.ast.identifier_name(SPAN, &ident.name);
+                    Some(
+                        self.ctx.ast.member_expression_static(SPAN, object, property, false).into(),
+                    )
+                } else if self.non_constant_members.contains(&ident.name) {
+                    // enum_name["identifier"]
+                    let object = self.create_enum_reference();
+                    let property = self.ctx.ast.expression_string_literal(SPAN, &ident.name);
+                    Some(
+                        self.ctx
+                            .ast
+                            .member_expression_computed(SPAN, object, property, false)
+                            .into(),
+                    )
+                } else if self.is_bound(ident) {
+                    // If the identifier is binding in current/parent scopes,
+                    // and it is not a member of the enum,
+                    // we don't need to rename it.
+                    // `var c = 1; enum A { a = c }` -> `var c = 1; enum A { a = c }
                     return;
+                } else {
+                    // enum_name.identifier
+                    let object = self.create_enum_reference();
+                    let property = self.ctx.ast.identifier_name(SPAN, &ident.name);
+                    Some(
+                        self.ctx.ast.member_expression_static(SPAN, object, property, false).into(),
+                    )
                 }
-
-                // TODO: shadowed case, e.g. let ident = 1; ident; // ident is not an enum
-                // enum_name.identifier
-                let object = self.ctx.ast.expression_identifier_reference(SPAN, &self.enum_name);
-                let property = self.ctx.ast.identifier_name(SPAN, &ident.name);
-                Some(self.ctx.ast.member_expression_static(SPAN, object, property, false).into())
             }
             _ => None,
         };
         if let Some(new_expr) = new_expr {
+            let mut collector = ReferenceCollector::default();
+            collector.visit_expression(expr);
+            for (reference_id, name) in collector.references {
+                self.ctx.delete_reference(reference_id, &name);
+            }
             *expr = new_expr;
         } else {
             walk_mut::walk_expression(self, expr);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_codegen::CodeGenerator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{EnumMemberValue, EnumMemberValues, TransformOptions, Transformer};
+
+    #[test]
+    fn member_values() {
+        let source_text = "
+            enum Direction { Up = 1, Down, Left = Up * 2.5, Right = -0 }
+            const enum Names { A = 'a', B = A + 'b', C = foo(), D = Names.B }
+        ";
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+        let (symbols, scopes) = SemanticBuilder::new(source_text)
+            .build(&program)
+            .semantic
+            .into_symbol_table_and_scope_tree();
+        let ret = Transformer::new(
+            &allocator,
+            Path::new("test.ts"),
+            source_type,
+            source_text,
+            ret.trivias,
+            TransformOptions::default(),
+        )
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+        assert!(ret.errors.is_empty());
+
+        let enum_values =
+            |enum_name: &str, members: Vec<(&str, EnumMemberValue)>| EnumMemberValues {
+                enum_name: enum_name.into(),
+                members: members.into_iter().map(|(name, value)| (name.into(), value)).collect(),
+            };
+        assert_eq!(
+            ret.enums,
+            vec![
+                enum_values(
+                    "Direction",
+                    vec![
+                        ("Up", EnumMemberValue::Number(1.0)),
+                        ("Down", EnumMemberValue::Number(2.0)),
+                        ("Left", EnumMemberValue::Number(2.5)),
+                        ("Right", EnumMemberValue::Number(-0.0)),
+                    ]
+                ),
+                enum_values(
+                    "Names",
+                    vec![
+                        ("A", EnumMemberValue::String("a".into())),
+                        ("B", EnumMemberValue::String("ab".into())),
+                        ("C", EnumMemberValue::Opaque),
+                        ("D", EnumMemberValue::String("ab".into())),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn member_source_mappings() {
+        let source_text = "enum Foo {\n  A = 1,\n  B = A + 1,\n  C,\n}\n";
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+        let (symbols, scopes) = SemanticBuilder::new(source_text)
+            .build(&program)
+            .semantic
+            .into_symbol_table_and_scope_tree();
+        let ret = Transformer::new(
+            &allocator,
+            Path::new("test.ts"),
+            source_type,
+            source_text,
+            ret.trivias,
+            TransformOptions::default(),
+        )
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+        assert!(ret.errors.is_empty());
+
+        let ret = CodeGenerator::new().enable_source_map("test.ts", source_text).build(&program);
+        let source_map = ret.source_map.unwrap();
+
+        // Everything printed for a member maps back to the member in the TypeScript source,
+        // except the references to the enum, which map to its name (`Foo` at 0:5).
+        for (name, src_line) in [("A", 1), ("B", 2), ("C", 3)] {
+            let dst_line = ret
+                .source_text
+                .lines()
+                .position(|line| line.ends_with(&format!("= \"{name}\";")))
+                .unwrap();
+            let src_lines = source_map
+                .get_source_view_tokens()
+                .filter(|token| {
+                    token.get_dst_line() as usize == dst_line
+                        && (token.get_src_line(), token.get_src_col()) != (0, 5)
+                })
+                .map(|token| token.get_src_line())
+                .collect::<Vec<_>>();
+            assert!(!src_lines.is_empty());
+            assert!(src_lines.iter().all(|line| *line == src_line), "{name}: {src_lines:?}");
+        }
+    }
+}
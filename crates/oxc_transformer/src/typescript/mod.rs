@@ -1,4 +1,8 @@
 mod annotations;
+mod auto_accessors;
+mod const_eval;
+mod decorator_metadata;
+mod decorators;
 mod diagnostics;
 mod r#enum;
 mod module;
@@ -15,8 +19,15 @@ use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
 use rewrite_extensions::TypeScriptRewriteExtensions;
 
-pub use self::options::{RewriteExtensionsMode, TypeScriptOptions};
-use self::{annotations::TypeScriptAnnotations, r#enum::TypeScriptEnum};
+use self::{
+    annotations::TypeScriptAnnotations, auto_accessors::TypeScriptAutoAccessors,
+    decorator_metadata::TypeScriptDecoratorMetadata, decorators::TypeScriptDecorators,
+    r#enum::TypeScriptEnum,
+};
+pub use self::{
+    options::{EnumOutputStyle, ImportsNotUsedAsValues, RewriteExtensionsMode, TypeScriptOptions},
+    r#enum::{EnumMemberValue, EnumMemberValues},
+};
 use crate::context::Ctx;
 
 /// [Preset TypeScript](https://babeljs.io/docs/babel-preset-typescript)
@@ -45,6 +56,9 @@ pub struct TypeScript<'a> {
     ctx: Ctx<'a>,
 
     annotations: TypeScriptAnnotations<'a>,
+    auto_accessors: TypeScriptAutoAccessors<'a>,
+    decorator_metadata: TypeScriptDecoratorMetadata<'a>,
+    decorators: TypeScriptDecorators<'a>,
     r#enum: TypeScriptEnum<'a>,
     namespace: TypeScriptNamespace<'a>,
     module: TypeScriptModule<'a>,
@@ -57,12 +71,15 @@ impl<'a> TypeScript<'a> {
 
         Self {
             annotations: TypeScriptAnnotations::new(Rc::clone(&options), Rc::clone(&ctx)),
-            r#enum: TypeScriptEnum::new(Rc::clone(&ctx)),
+            auto_accessors: TypeScriptAutoAccessors::new(),
+            decorator_metadata: TypeScriptDecoratorMetadata::new(Rc::clone(&ctx)),
+            decorators: TypeScriptDecorators::new(Rc::clone(&ctx)),
+            r#enum: TypeScriptEnum::new(Rc::clone(&options), Rc::clone(&ctx)),
             rewrite_extensions: TypeScriptRewriteExtensions::new(
                 options.rewrite_import_extensions.clone().unwrap_or_default(),
             ),
             namespace: TypeScriptNamespace::new(Rc::clone(&options), Rc::clone(&ctx)),
-            module: TypeScriptModule::new(Rc::clone(&ctx)),
+            module: TypeScriptModule::new(Rc::clone(&options), Rc::clone(&ctx)),
             options,
             ctx,
         }
@@ -77,12 +94,21 @@ impl<'a> Traverse<'a> for TypeScript<'a> {
             program.hashbang = None;
             program.body.clear();
         } else {
+            self.r#enum.enter_program(program, ctx);
             self.namespace.enter_program(program, ctx);
+            self.r#enum.set_namespace_names(self.namespace.take_declared_names());
+            self.r#enum.set_namespace_exported_enums(self.namespace.take_exported_enums());
         }
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.exit_program(program, ctx);
+        self.r#enum.exit_program(program, ctx);
+        let helpers =
+            self.ctx.runtime_helpers.get_helper_statements(self.options.external_helpers, ctx);
+        if !helpers.is_empty() {
+            program.body.splice(0..0, helpers);
+        }
     }
 
     fn enter_arrow_function_expression(
@@ -102,6 +128,16 @@ impl<'a> Traverse<'a> for TypeScript<'a> {
     }
 
     fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.experimental_decorators {
+            self.decorators.enter_class(class, ctx);
+        }
+        // Metadata is only emitted for legacy decorators, the same as TypeScript
+        if self.options.experimental_decorators && self.options.emit_decorator_metadata {
+            self.decorator_metadata.enter_class(class, ctx);
+        }
+        if self.options.lower_auto_accessors {
+            self.auto_accessors.enter_class(class, ctx);
+        }
         self.annotations.enter_class(class, ctx);
     }
 
@@ -119,6 +155,10 @@ impl<'a> Traverse<'a> for TypeScript<'a> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.r#enum.enter_expression(expr, ctx);
+        if self.options.rewrite_import_extensions.is_some() {
+            self.rewrite_extensions.enter_expression(expr, ctx);
+        }
     }
 
     fn enter_simple_assignment_target(
@@ -195,14 +235,28 @@ impl<'a> Traverse<'a> for TypeScript<'a> {
 
     fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_statements(stmts, ctx);
+        self.r#enum.enter_statements(stmts, ctx);
+        if self.options.lower_auto_accessors {
+            self.auto_accessors.enter_statements(stmts, ctx);
+        }
     }
 
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.exit_statements(stmts, ctx);
+        if self.options.lower_auto_accessors {
+            self.auto_accessors.exit_statements(stmts, ctx);
+        }
+        if self.options.experimental_decorators {
+            self.decorators.exit_statements(stmts, ctx);
+        }
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.r#enum.enter_statement(stmt, ctx);
+        if self.options.rewrite_import_extensions.is_some() {
+            self.rewrite_extensions.enter_statement(stmt, ctx);
+        }
+        self.module.enter_statement(stmt, ctx);
     }
 
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -1,18 +1,22 @@
+use std::rc::Rc;
+
 use oxc_allocator::Box;
 use oxc_ast::{ast::*, NONE};
-use oxc_span::SPAN;
-use oxc_syntax::reference::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{operator::AssignmentOperator, reference::ReferenceFlags};
 use oxc_traverse::{Traverse, TraverseCtx};
 
+use super::{namespace::is_value_import_equals, TypeScriptOptions};
 use crate::context::Ctx;
 
 pub struct TypeScriptModule<'a> {
+    options: Rc<TypeScriptOptions>,
     ctx: Ctx<'a>,
 }
 
 impl<'a> TypeScriptModule<'a> {
-    pub fn new(ctx: Ctx<'a>) -> Self {
-        Self { ctx }
+    pub fn new(options: Rc<TypeScriptOptions>, ctx: Ctx<'a>) -> Self {
+        Self { options, ctx }
     }
 }
 
@@ -27,10 +31,43 @@ impl<'a> Traverse<'a> for TypeScriptModule<'a> {
     /// ```
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
         match decl {
+            // Imports of types are removed with other TypeScript-only declarations
             Declaration::TSImportEqualsDeclaration(ts_import_equals)
-                if ts_import_equals.import_kind.is_value() =>
+                if is_value_import_equals(ts_import_equals, ctx.current_scope_id(), ctx) =>
             {
-                *decl = self.transform_ts_import_equals(ts_import_equals, ctx);
+                *decl = self.transform_ts_import_equals(ts_import_equals, false, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// ```TypeScript
+    /// export = foo;
+    /// export import Foo = require("foo");
+    /// ```
+    /// ```JavaScript
+    /// module.exports = foo;
+    /// var Foo = exports.Foo = require("foo");
+    /// ```
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `export = <value>` in ES modules is reported in `enter_ts_export_assignment`,
+        // and `export import Foo = ...` is kept as `export var Foo = ...`
+        if self.ctx.source_type.is_module() {
+            return;
+        }
+        match stmt {
+            Statement::TSExportAssignment(export_assignment) => {
+                *stmt = Self::transform_ts_export_assignment(export_assignment, ctx);
+            }
+            Statement::ExportNamedDeclaration(export_decl) => {
+                if let Some(Declaration::TSImportEqualsDeclaration(ts_import_equals)) =
+                    &mut export_decl.declaration
+                {
+                    if is_value_import_equals(ts_import_equals, ctx.current_scope_id(), ctx) {
+                        let decl = self.transform_ts_import_equals(ts_import_equals, true, ctx);
+                        *stmt = Statement::from(decl);
+                    }
+                }
             }
             _ => {}
         }
@@ -49,9 +86,12 @@ impl<'a> Traverse<'a> for TypeScriptModule<'a> {
 }
 
 impl<'a> TypeScriptModule<'a> {
+    /// `is_commonjs_export` assigns the value to `exports` as well, for
+    /// `export import Foo = ...` in scripts.
     fn transform_ts_import_equals(
         &self,
         decl: &mut Box<'a, TSImportEqualsDeclaration<'a>>,
+        is_commonjs_export: bool,
         ctx: &mut TraverseCtx<'a>,
     ) -> Declaration<'a> {
         let kind = VariableDeclarationKind::Var;
@@ -72,19 +112,61 @@ impl<'a> TypeScriptModule<'a> {
                         ));
                     }
 
-                    let callee = ctx.ast.expression_identifier_reference(SPAN, "require");
+                    let callee = ctx
+                        .ast
+                        .expression_identifier_reference(SPAN, self.options.require_name.as_ref());
                     let arguments = ctx.ast.vec1(Argument::from(
                         ctx.ast.expression_from_string_literal(reference.expression.clone()),
                     ));
                     ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
                 }
             };
+            // exports.Foo = init
+            let init = if is_commonjs_export {
+                let exports = ctx.create_unbound_reference_id(
+                    SPAN,
+                    Atom::from("exports"),
+                    ReferenceFlags::Read,
+                );
+                let exports = ctx.ast.expression_from_identifier_reference(exports);
+                let property = ctx.ast.identifier_name(SPAN, &decl.id.name);
+                let target = ctx.ast.member_expression_static(SPAN, exports, property, false);
+                let target = ctx.ast.simple_assignment_target_member_expression(target);
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    init,
+                )
+            } else {
+                init
+            };
             ctx.ast.vec1(ctx.ast.variable_declarator(SPAN, kind, binding, Some(init), false))
         };
 
         ctx.ast.declaration_variable(SPAN, kind, decls, false)
     }
 
+    fn transform_ts_export_assignment(
+        export_assignment: &mut TSExportAssignment<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let module =
+            ctx.create_unbound_reference_id(SPAN, Atom::from("module"), ReferenceFlags::Read);
+        let module = ctx.ast.expression_from_identifier_reference(module);
+        let property = ctx.ast.identifier_name(SPAN, "exports");
+        let target = ctx.ast.member_expression_static(SPAN, module, property, false);
+        let target = ctx.ast.simple_assignment_target_member_expression(target);
+        let value = ctx.ast.move_expression(&mut export_assignment.expression);
+        let expr = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            value,
+        );
+        ctx.ast.statement_expression(export_assignment.span, expr)
+    }
+
     #[allow(clippy::only_used_in_recursion)]
     fn transform_ts_type_name(
         &self,
@@ -2,29 +2,50 @@ use std::rc::Rc;
 
 use oxc_allocator::{Box, Vec};
 use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, NONE};
-use oxc_span::{Atom, CompactStr, SPAN};
+use oxc_span::{Atom, CompactStr, GetSpan, Span, SPAN};
 use oxc_syntax::{
     operator::{AssignmentOperator, LogicalOperator},
     scope::{ScopeFlags, ScopeId},
-    symbol::SymbolFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
 use oxc_traverse::{Traverse, TraverseCtx};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::{
     diagnostics::{ambient_module_nested, namespace_exporting_non_const, namespace_not_supported},
     TypeScriptOptions,
 };
-use crate::context::Ctx;
+use crate::{
+    context::Ctx,
+    helpers::{bindings::BoundIdentifier, runtime_helpers::RuntimeHelper},
+};
 
 pub struct TypeScriptNamespace<'a> {
     ctx: Ctx<'a>,
     options: Rc<TypeScriptOptions>,
+    /// Names of the top-level namespaces this transform declared a `let` binding for.
+    /// Enums merging with these namespaces reuse the binding instead of declaring another one.
+    declared_names: FxHashSet<Atom<'a>>,
+    /// Enums exported from namespaces, and the parameter names of their namespaces' functions.
+    exported_enums: FxHashMap<SymbolId, Atom<'a>>,
 }
 
 impl<'a> TypeScriptNamespace<'a> {
     pub fn new(options: Rc<TypeScriptOptions>, ctx: Ctx<'a>) -> Self {
-        Self { ctx, options }
+        Self {
+            ctx,
+            options,
+            declared_names: FxHashSet::default(),
+            exported_enums: FxHashMap::default(),
+        }
+    }
+
+    pub fn take_declared_names(&mut self) -> FxHashSet<Atom<'a>> {
+        std::mem::take(&mut self.declared_names)
+    }
+
+    pub fn take_exported_enums(&mut self) -> FxHashMap<SymbolId, Atom<'a>> {
+        std::mem::take(&mut self.exported_enums)
     }
 }
 
@@ -49,10 +70,6 @@ impl<'a> Traverse<'a> for TypeScriptNamespace<'a> {
             match stmt {
                 Statement::TSModuleDeclaration(decl) => {
                     if !decl.declare {
-                        if !self.options.allow_namespaces {
-                            self.ctx.error(namespace_not_supported(decl.span));
-                        }
-
                         if let Some(transformed_stmt) = self.handle_nested(
                             {
                                 // SAFETY: `ast.copy` is unsound! We need to fix.
@@ -61,8 +78,10 @@ impl<'a> Traverse<'a> for TypeScriptNamespace<'a> {
                             None,
                             ctx,
                         ) {
+                            self.report_namespace_not_supported(&decl);
                             let name = decl.id.name();
                             if names.insert(name.clone()) {
+                                self.declared_names.insert(name.clone());
                                 new_stmts
                                     .push(Statement::from(self.create_variable_declaration(name)));
                             }
@@ -77,10 +96,6 @@ impl<'a> Traverse<'a> for TypeScriptNamespace<'a> {
                     match &export_decl.declaration {
                         Some(Declaration::TSModuleDeclaration(decl)) => {
                             if !decl.declare {
-                                if !self.options.allow_namespaces {
-                                    self.ctx.error(namespace_not_supported(decl.span));
-                                }
-
                                 if let Some(transformed_stmt) = self.handle_nested(
                                     {
                                         // SAFETY: `ast.copy` is unsound! We need to fix.
@@ -89,8 +104,10 @@ impl<'a> Traverse<'a> for TypeScriptNamespace<'a> {
                                     None,
                                     ctx,
                                 ) {
+                                    self.report_namespace_not_supported(decl);
                                     let name = decl.id.name();
                                     if names.insert(name.clone()) {
+                                        self.declared_names.insert(name.clone());
                                         let declaration = self.create_variable_declaration(name);
                                         let export_named_decl = self
                                             .ctx
@@ -123,6 +140,18 @@ impl<'a> Traverse<'a> for TypeScriptNamespace<'a> {
                         _ => {}
                     }
                 }
+                // `export default class Foo {}` and `export default function Foo() {}`
+                // also declare a binding that a following namespace merges into
+                Statement::ExportDefaultDeclaration(ref export_decl) => {
+                    let id = match &export_decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+                        _ => None,
+                    };
+                    if let Some(id) = id {
+                        names.insert(id.name.clone());
+                    }
+                }
                 // Collect bindings from class, function and enum declarations
                 Statement::FunctionDeclaration(_)
                 | Statement::ClassDeclaration(_)
@@ -140,15 +169,31 @@ impl<'a> Traverse<'a> for TypeScriptNamespace<'a> {
 }
 
 impl<'a> TypeScriptNamespace<'a> {
+    /// Only instantiated namespaces are reported, type-only namespaces are removed regardless
+    /// of `allow_namespaces`.
+    fn report_namespace_not_supported(&self, decl: &TSModuleDeclaration<'a>) {
+        if !self.options.allow_namespaces {
+            // `namespace Foo {}`
+            //  ^^^^^^^^^^^^^
+            let span = Span::new(decl.span.start, decl.id.span().end);
+            self.ctx.error(namespace_not_supported(span));
+        }
+    }
+
     fn handle_nested(
-        &self,
+        &mut self,
         decl: TSModuleDeclaration<'a>,
         parent_export: Option<Expression<'a>>,
-        ctx: &mut TraverseCtx,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Option<Statement<'a>> {
         // Skip empty declaration e.g. `namespace x;`
         let body = decl.body?;
 
+        // `global {}` augments the global scope, it is ambient even without `declare`
+        if decl.kind.is_global() {
+            return None;
+        }
+
         let mut names: FxHashSet<Atom<'a>> = FxHashSet::default();
 
         let TSModuleDeclarationName::Identifier(IdentifierName { name: real_name, .. }) = decl.id
@@ -187,6 +232,11 @@ impl<'a> TypeScriptNamespace<'a> {
         let mut new_stmts = self.ctx.ast.vec();
 
         for stmt in namespace_top_level {
+            // Ambient declarations (e.g. `declare const x: number`) do not instantiate the namespace
+            if stmt.as_declaration().is_some_and(Declaration::declare) {
+                continue;
+            }
+
             match stmt {
                 Statement::TSModuleDeclaration(decl) => {
                     if decl.id.is_string_literal() {
@@ -205,14 +255,28 @@ impl<'a> TypeScriptNamespace<'a> {
                     }
                     continue;
                 }
+                Statement::ExportAllDeclaration(export_decl) => {
+                    if let Some(stmt) = self.handle_export_all(&export_decl, &name, ctx) {
+                        new_stmts.push(stmt);
+                    }
+                    continue;
+                }
                 Statement::ExportNamedDeclaration(export_decl) => {
                     // NB: `ExportNamedDeclaration` with no declaration (e.g. `export {x}`) is not
                     // legal syntax in TS namespaces
                     let export_decl = export_decl.unbox();
+                    if export_decl.source.is_some() {
+                        self.handle_re_export(&export_decl, &name, scope_id, &mut new_stmts, ctx);
+                        continue;
+                    }
                     if let Some(decl) = export_decl.declaration {
                         if decl.declare() {
                             continue;
                         }
+                        if let Declaration::TSEnumDeclaration(enum_decl) = &decl {
+                            self.exported_enums
+                                .insert(enum_decl.id.symbol_id.get().unwrap(), name.clone());
+                        }
                         match decl {
                             Declaration::TSEnumDeclaration(_)
                             | Declaration::FunctionDeclaration(_)
@@ -234,6 +298,16 @@ impl<'a> TypeScriptNamespace<'a> {
                                     self.handle_variable_declaration(var_decl, name.clone());
                                 new_stmts.extend(stmts);
                             }
+                            Declaration::TSImportEqualsDeclaration(ref import_equals)
+                                if is_value_import_equals(import_equals, scope_id, ctx) =>
+                            {
+                                self.add_declaration(
+                                    decl,
+                                    name.clone(),
+                                    &mut names,
+                                    &mut new_stmts,
+                                );
+                            }
                             Declaration::TSModuleDeclaration(module_decl) => {
                                 if module_decl.id.is_string_literal() {
                                     self.ctx.error(ambient_module_nested(module_decl.span));
@@ -265,6 +339,8 @@ impl<'a> TypeScriptNamespace<'a> {
                 | Statement::TSEnumDeclaration(_) => {
                     names.insert(stmt.to_declaration().id().as_ref().unwrap().name.clone());
                 }
+                Statement::TSImportEqualsDeclaration(ref import_equals)
+                    if is_value_import_equals(import_equals, scope_id, ctx) => {}
                 Statement::TSTypeAliasDeclaration(_)
                 | Statement::TSInterfaceDeclaration(_)
                 | Statement::TSImportEqualsDeclaration(_) => continue,
@@ -412,8 +488,135 @@ impl<'a> TypeScriptNamespace<'a> {
         self.ctx.ast.statement_expression(SPAN, expr)
     }
 
+    /// `export * from "mod"` -> `_exportStar(require("mod"), _N)`
+    /// `export * as ns from "mod"` -> `_N.ns = require("mod")`
+    fn handle_export_all(
+        &self,
+        decl: &ExportAllDeclaration<'a>,
+        name: &Atom<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        if decl.export_kind.is_type() {
+            return None;
+        }
+        let source = ctx.ast.expression_from_string_literal(decl.source.clone());
+        let module = self.create_require_call(source, ctx);
+        let namespace = self.ctx.ast.expression_identifier_reference(SPAN, name);
+        let expr = if let Some(exported) = &decl.exported {
+            let target = self.create_namespace_member(namespace, exported);
+            self.ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                module,
+            )
+        } else {
+            let callee = self.ctx.runtime_helpers.create_reference(RuntimeHelper::ExportStar, ctx);
+            let arguments =
+                self.ctx.ast.vec_from_iter([Argument::from(module), Argument::from(namespace)]);
+            self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        };
+        Some(self.ctx.ast.statement_expression(SPAN, expr))
+    }
+
+    /// `export { a, b as c } from "mod"` ->
+    /// `var _mod = require("mod"); _createBinding(_N, _mod, "a"); _createBinding(_N, _mod, "b", "c");`
+    ///
+    /// The bindings are live, the same as re-exports from ES modules.
+    fn handle_re_export(
+        &self,
+        decl: &ExportNamedDeclaration<'a>,
+        name: &Atom<'a>,
+        scope_id: ScopeId,
+        new_stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(source) = &decl.source else { return };
+        if decl.export_kind.is_type()
+            || decl.specifiers.iter().all(|specifier| specifier.export_kind.is_type())
+        {
+            return;
+        }
+
+        // `var _mod = require("mod");`
+        let source = ctx.ast.expression_from_string_literal(source.clone());
+        let symbol_id =
+            ctx.generate_uid_based_on_node(&source, scope_id, SymbolFlags::FunctionScopedVariable);
+        let binding =
+            BoundIdentifier { name: ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id };
+        let declaration = {
+            let kind = VariableDeclarationKind::Var;
+            let id = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(
+                    binding.create_binding_identifier(),
+                ),
+                NONE,
+                false,
+            );
+            let init = self.create_require_call(source, ctx);
+            let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+            ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false)
+        };
+        new_stmts.push(Statement::from(declaration));
+
+        for specifier in &decl.specifiers {
+            if specifier.export_kind.is_type() {
+                continue;
+            }
+            let local = specifier.local.name();
+            let exported = specifier.exported.name();
+            let mut arguments = ctx.ast.vec_from_iter([
+                Argument::from(ctx.ast.expression_identifier_reference(SPAN, name)),
+                Argument::from(
+                    ctx.ast
+                        .expression_from_identifier_reference(binding.create_read_reference(ctx)),
+                ),
+                Argument::from(ctx.ast.expression_string_literal(SPAN, local.clone())),
+            ]);
+            if exported != local {
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, exported)));
+            }
+            let callee =
+                self.ctx.runtime_helpers.create_reference(RuntimeHelper::CreateBinding, ctx);
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            new_stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+    }
+
+    /// `require("mod")`, calling `require_name`
+    fn create_require_call(
+        &self,
+        source: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee =
+            ctx.ast.expression_identifier_reference(SPAN, self.options.require_name.as_ref());
+        let arguments = ctx.ast.vec1(Argument::from(source));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `_N.name`, or `_N["not ident"]` for string literal names
+    fn create_namespace_member(
+        &self,
+        namespace: Expression<'a>,
+        name: &ModuleExportName<'a>,
+    ) -> SimpleAssignmentTarget<'a> {
+        if let ModuleExportName::StringLiteral(literal) = name {
+            let property = self.ctx.ast.expression_from_string_literal(literal.clone());
+            self.ctx.ast.simple_assignment_target_member_expression(
+                self.ctx.ast.member_expression_computed(SPAN, namespace, property, false),
+            )
+        } else {
+            let property = self.ctx.ast.identifier_name(SPAN, name.name());
+            self.ctx.ast.simple_assignment_target_member_expression(
+                self.ctx.ast.member_expression_static(SPAN, namespace, property, false),
+            )
+        }
+    }
+
     /// Add assignment statement for decl id
     /// function id() {} -> function id() {}; Name.id = id;
+    /// import id = X.Y; -> var id = X.Y; Name.id = id;
     fn add_declaration(
         &self,
         decl: Declaration<'a>,
@@ -514,3 +717,33 @@ fn has_namespace(stmts: &[Statement]) -> bool {
         _ => false,
     })
 }
+
+/// Check if `import X = Y` refers to a value, otherwise it is elided like other type-only
+/// declarations. `Y` is considered a type if its first name resolves to an interface,
+/// a type alias, a type-only import or an uninstantiated namespace.
+pub(super) fn is_value_import_equals(
+    decl: &TSImportEqualsDeclaration,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx,
+) -> bool {
+    if decl.import_kind.is_type() {
+        return false;
+    }
+    let Some(type_name) = decl.module_reference.as_ts_type_name() else {
+        // `import X = require("mod")`
+        return true;
+    };
+    // Look up the binding by name, as a reference to a type-only symbol is left unresolved
+    let ident = TSTypeName::get_first_name(type_name);
+    let Some(symbol_id) = ctx.scopes().find_binding(scope_id, &ident.name) else {
+        return true;
+    };
+    let flags = ctx.symbols().get_flags(symbol_id);
+    flags.intersects(SymbolFlags::Value | SymbolFlags::Function | SymbolFlags::Import)
+        || !flags.intersects(
+            SymbolFlags::TypeAlias
+                | SymbolFlags::Interface
+                | SymbolFlags::TypeImport
+                | SymbolFlags::NameSpaceModule,
+        )
+}
@@ -11,6 +11,10 @@ fn default_for_jsx_pragma() -> Cow<'static, str> {
     Cow::Borrowed("React.createElement")
 }
 
+fn default_for_require_name() -> Cow<'static, str> {
+    Cow::Borrowed("require")
+}
+
 fn default_for_jsx_pragma_frag() -> Cow<'static, str> {
     Cow::Borrowed("React.Fragment")
 }
@@ -38,17 +42,123 @@ pub struct TypeScriptOptions {
     /// This should only be used if you are using TypeScript >= 3.8.
     pub only_remove_type_imports: bool,
 
-    // Enables compilation of TypeScript namespaces.
+    /// When enabled, an `export {}` is added to files whose imports and exports are all removed,
+    /// so they are still treated as modules. When disabled, such files become scripts.
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub add_esm_marker: bool,
+
+    // Enables compilation of TypeScript namespaces. When disabled, instantiated namespaces are
+    // reported as errors, while type-only namespaces are still removed.
     #[serde(default = "default_as_true")]
     pub allow_namespaces: bool,
 
-    // When enabled, type-only class fields are only removed if they are prefixed with the declare modifier:
+    /// When enabled, type-only class fields are only removed if they are prefixed with the
+    /// `declare` modifier, and other fields (`x: number`) are kept. When disabled, type-only
+    /// fields are initialized to `undefined` instead (`this.x = void 0` in the constructor, or
+    /// `static x = void 0`), the same as older versions of TypeScript.
+    /// `declare` fields are always removed. Defaults to `true`.
     #[serde(default = "default_as_true")]
     pub allow_declare_fields: bool,
 
-    /// Unused.
+    /// When disabled, instance fields are initialized in the constructor with assignments
+    /// (`x = 1` -> `this.x = 1`) instead of being defined as class fields, the same as
+    /// TypeScript's `useDefineForClassFields` option. Static, private, computed and decorated
+    /// fields are kept as class fields. Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub use_define_for_class_fields: bool,
+
+    /// When enabled, `const enum` member accesses are replaced with their constant values,
+    /// and the `const enum` declarations are removed. Exported or otherwise referenced
+    /// `const enum`s are kept as object literals. References to previous constant members
+    /// in enum initializers (`B = A + x`) are replaced with their values too.
     pub optimize_const_enums: bool,
 
+    /// When enabled, `const enum` declarations are transformed the same as regular enums,
+    /// so the enum objects exist at runtime, the same as TypeScript's `preserveConstEnums` option.
+    /// Member accesses are not inlined by `optimize_const_enums` in this mode,
+    /// except for `declare const enum`s which have no runtime object.
+    pub preserve_const_enums: bool,
+
+    /// Decides what happens to value imports which are only used as types, the same as
+    /// TypeScript's `importsNotUsedAsValues` option. Defaults to
+    /// [`ImportsNotUsedAsValues::Remove`]. `import type` declarations are always removed.
+    pub imports_not_used_as_values: ImportsNotUsedAsValues,
+
+    /// When enabled, constructs which can't be transformed correctly one file at a time are
+    /// reported, the same as TypeScript's `isolatedModules` option: re-exports of types without
+    /// `export type`, and exported `const enum`s.
+    pub isolated_modules: bool,
+
+    /// When enabled, specifiers of `export { A } from 'mod'` without a `type` modifier are reported,
+    /// unless they can be resolved to a local value, as they may re-export types, which can't be
+    /// known without checking `mod`. This is stricter than [`TypeScriptOptions::isolated_modules`],
+    /// and has no effect with [`TypeScriptOptions::only_remove_type_imports`], where re-exports
+    /// are kept as written.
+    pub report_ambiguous_re_exports: bool,
+
+    /// When enabled together with [`TypeScriptOptions::only_remove_type_imports`], value imports
+    /// which are only referenced as types are reported, as keeping them imports bindings which
+    /// may not exist at runtime. This is the same error as TypeScript reports with
+    /// `verbatimModuleSyntax`.
+    pub report_type_only_imports: bool,
+
+    /// When enabled, design-time type metadata (`design:type`, `design:paramtypes` and
+    /// `design:returntype`) is emitted for decorated classes and class members, the same as
+    /// TypeScript's `emitDecoratorMetadata` option.
+    ///
+    /// This only takes effect together with `experimental_decorators`.
+    pub emit_decorator_metadata: bool,
+
+    /// When enabled, legacy decorators are lowered to `_decorate` helper calls,
+    /// the same as TypeScript's `experimentalDecorators` option.
+    pub experimental_decorators: bool,
+
+    /// When enabled, runtime helpers such as `_decorate` are imported from `tslib`
+    /// (`import { __decorate as _decorate } from "tslib"`) instead of being defined in every file,
+    /// the same as TypeScript's `importHelpers` option. Scripts load them with
+    /// `const { __decorate: _decorate } = require("tslib")` instead. Only the used helpers are loaded.
+    ///
+    /// This covers every helper the TypeScript transform emits: `__decorate`, `__param` and
+    /// `__metadata` for decorators, and `__exportStar` and `__createBinding` for re-exports
+    /// in namespaces.
+    pub external_helpers: bool,
+
+    /// When enabled, auto-accessors (`accessor x = 1`) are lowered to a private field with a getter
+    /// and setter, for targets without native support. Decorators of auto-accessors are moved to
+    /// the getter, so they are lowered with the other legacy decorators with
+    /// [`TypeScriptOptions::experimental_decorators`].
+    pub lower_auto_accessors: bool,
+
+    /// When enabled, the IIFEs emitted for enums are annotated with `/* #__PURE__ */`,
+    /// so bundlers can remove unused enums. Enums with members that may have side effects
+    /// (e.g. `A = foo()`) are not annotated.
+    pub annotate_pure_enums: bool,
+
+    /// When enabled, enums whose members are all constant are emitted as object literals
+    /// with their reverse mappings (`var Foo = { X: 0, 0: "X" };`) instead of IIFEs, which is
+    /// smaller and easier for minifiers to optimize. Enums merged with other enums or namespaces,
+    /// or exported from namespaces, are still emitted as IIFEs. Enums with only string members
+    /// don't have reverse mappings, so they are emitted as plain objects (`var Dir = { Up: "up" };`).
+    /// Only applies to [`EnumOutputStyle::Compact`].
+    pub constant_enums_as_objects: bool,
+
+    /// When enabled, enum objects are wrapped in `Object.freeze(...)`, so accidental mutations
+    /// of them fail at runtime. Enums merged with other enums or namespaces, or exported from
+    /// namespaces, are not frozen, as the objects are added to after they are created.
+    /// Enums emitted with [`EnumOutputStyle::Classic`] are not frozen either, as they are
+    /// created by a statement.
+    pub freeze_enums: bool,
+
+    /// Decides the shape of the code emitted for enums. Defaults to [`EnumOutputStyle::Compact`].
+    pub enum_output_style: EnumOutputStyle,
+
+    /// Replace the function called to load external modules in
+    /// `import foo = require("foo")` declarations, for environments where `require`
+    /// is shadowed or renamed. Defaults to `require`.
+    #[serde(default = "default_for_require_name")]
+    pub require_name: Cow<'static, str>,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -61,6 +171,138 @@ pub struct TypeScriptOptions {
 }
 
 impl TypeScriptOptions {
+    #[must_use]
+    pub fn with_only_remove_type_imports(mut self, enable: bool) -> Self {
+        self.only_remove_type_imports = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_add_esm_marker(mut self, enable: bool) -> Self {
+        self.add_esm_marker = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_allow_namespaces(mut self, enable: bool) -> Self {
+        self.allow_namespaces = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_allow_declare_fields(mut self, enable: bool) -> Self {
+        self.allow_declare_fields = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_use_define_for_class_fields(mut self, enable: bool) -> Self {
+        self.use_define_for_class_fields = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_optimize_const_enums(mut self, enable: bool) -> Self {
+        self.optimize_const_enums = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_preserve_const_enums(mut self, enable: bool) -> Self {
+        self.preserve_const_enums = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_isolated_modules(mut self, enable: bool) -> Self {
+        self.isolated_modules = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_ambiguous_re_exports(mut self, enable: bool) -> Self {
+        self.report_ambiguous_re_exports = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_type_only_imports(mut self, enable: bool) -> Self {
+        self.report_type_only_imports = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_emit_decorator_metadata(mut self, enable: bool) -> Self {
+        self.emit_decorator_metadata = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_experimental_decorators(mut self, enable: bool) -> Self {
+        self.experimental_decorators = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_external_helpers(mut self, enable: bool) -> Self {
+        self.external_helpers = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_lower_auto_accessors(mut self, enable: bool) -> Self {
+        self.lower_auto_accessors = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_annotate_pure_enums(mut self, enable: bool) -> Self {
+        self.annotate_pure_enums = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_constant_enums_as_objects(mut self, enable: bool) -> Self {
+        self.constant_enums_as_objects = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_freeze_enums(mut self, enable: bool) -> Self {
+        self.freeze_enums = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_imports_not_used_as_values(
+        mut self,
+        imports_not_used_as_values: ImportsNotUsedAsValues,
+    ) -> Self {
+        self.imports_not_used_as_values = imports_not_used_as_values;
+        self
+    }
+
+    #[must_use]
+    pub fn with_enum_output_style(mut self, enum_output_style: EnumOutputStyle) -> Self {
+        self.enum_output_style = enum_output_style;
+        self
+    }
+
+    #[must_use]
+    pub fn with_require_name<S: Into<Cow<'static, str>>>(mut self, require_name: S) -> Self {
+        self.require_name = require_name.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_rewrite_import_extensions(
+        mut self,
+        rewrite_import_extensions: Option<RewriteExtensionsMode>,
+    ) -> Self {
+        self.rewrite_import_extensions = rewrite_import_extensions;
+        self
+    }
+
     /// Scan through all comments and find the following pragmas
     ///
     /// * @jsx React.createElement
@@ -103,14 +345,56 @@ impl Default for TypeScriptOptions {
             jsx_pragma: default_for_jsx_pragma(),
             jsx_pragma_frag: default_for_jsx_pragma_frag(),
             only_remove_type_imports: false,
+            add_esm_marker: default_as_true(),
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
+            use_define_for_class_fields: default_as_true(),
             optimize_const_enums: false,
+            preserve_const_enums: false,
+            imports_not_used_as_values: ImportsNotUsedAsValues::default(),
+            isolated_modules: false,
+            report_ambiguous_re_exports: false,
+            report_type_only_imports: false,
+            emit_decorator_metadata: false,
+            experimental_decorators: false,
+            external_helpers: false,
+            lower_auto_accessors: false,
+            annotate_pure_enums: false,
+            constant_enums_as_objects: false,
+            freeze_enums: false,
+            enum_output_style: EnumOutputStyle::default(),
+            require_name: default_for_require_name(),
             rewrite_import_extensions: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportsNotUsedAsValues {
+    /// Imports whose specifiers are all unused or only used as types are removed.
+    #[default]
+    Remove,
+    /// Imports whose specifiers are all unused or only used as types are kept for their
+    /// side effects, as bare imports: `import { A } from "mod"` -> `import "mod"`.
+    Preserve,
+    /// The same as [`ImportsNotUsedAsValues::Preserve`], and imports with value specifiers
+    /// which are all unused or only used as types are reported, as they should use `import type`.
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EnumOutputStyle {
+    /// `var Foo = function(Foo) { ... }(Foo || {});`
+    #[default]
+    Compact,
+    /// `var Foo; (function(Foo) { ... })(Foo || (Foo = {}));`, the same as TypeScript's output.
+    /// The enum object is assigned back to the binding, so re-opened enums and merged namespaces
+    /// see the same object.
+    Classic,
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum RewriteExtensionsMode {
     /// Rewrite `.ts`/`.mts`/`.cts` extensions in import/export declarations to `.js`/`.mjs`/`.cjs`.
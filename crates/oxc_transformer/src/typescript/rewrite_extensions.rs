@@ -1,12 +1,15 @@
 //! Rewrite import extensions
 //!
-//! This plugin is used to rewrite/remove extensions from import/export source.
-//! It is only handled source that contains `/` or `\` in the source.
+//! This plugin is used to rewrite/remove extensions from import/export source,
+//! `import()` calls with a string literal source and `import x = require("source")`.
+//! It is only handled source that contains `/` or `\` in the source, and is not a URL.
+//! Query and hash suffixes (`./a.ts?raw`) are kept as is.
 //!
 //! Based on Babel's [plugin-rewrite-ts-imports](https://github.com/babel/babel/blob/3bcfee232506a4cebe410f02042fb0f0adeeb0b1/packages/babel-preset-typescript/src/plugin-rewrite-ts-imports.ts)
 
 use oxc_ast::ast::{
-    ExportAllDeclaration, ExportNamedDeclaration, ImportDeclaration, StringLiteral,
+    Declaration, ExportAllDeclaration, ExportNamedDeclaration, Expression, ImportDeclaration,
+    Statement, StringLiteral, TSModuleReference,
 };
 use oxc_traverse::{Traverse, TraverseCtx};
 
@@ -27,10 +30,13 @@ impl TypeScriptRewriteExtensions {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let value = source.value.as_str();
-        if !value.contains(['/', '\\']) {
+        if !value.contains(['/', '\\']) || value.contains("://") {
             return;
         }
 
+        // `./a.ts?raw#hash` -> `./a.ts` and `?raw#hash`
+        let (value, suffix) = value.split_at(value.find(['?', '#']).unwrap_or(value.len()));
+
         let Some((_, extension)) = value.rsplit_once('.') else { return };
 
         let replace = match extension {
@@ -41,13 +47,15 @@ impl TypeScriptRewriteExtensions {
         };
 
         let value = value.trim_end_matches(extension);
-        source.value = if self.mode.is_remove() {
-            ctx.ast.atom(value.trim_end_matches('.'))
+        let mut value = if self.mode.is_remove() {
+            value.trim_end_matches('.').to_string()
         } else {
             let mut value = value.to_string();
             value.push_str(replace);
-            ctx.ast.atom(&value)
+            value
         };
+        value.push_str(suffix);
+        source.value = ctx.ast.atom(&value);
     }
 }
 
@@ -86,4 +94,31 @@ impl<'a> Traverse<'a> for TypeScriptRewriteExtensions {
         }
         self.rewrite_extensions(&mut node.source, ctx);
     }
+
+    fn enter_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `import("./a.ts")`
+        if let Expression::ImportExpression(expr) = node {
+            if let Expression::StringLiteral(source) = &mut expr.source {
+                self.rewrite_extensions(source, ctx);
+            }
+        }
+    }
+
+    fn enter_statement(&mut self, node: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `import x = require("./a.ts")` and `export import x = require("./a.ts")`
+        let decl = match node {
+            Statement::TSImportEqualsDeclaration(decl) => decl,
+            Statement::ExportNamedDeclaration(export_decl) => match &mut export_decl.declaration {
+                Some(Declaration::TSImportEqualsDeclaration(decl)) => decl,
+                _ => return,
+            },
+            _ => return,
+        };
+        if decl.import_kind.is_type() {
+            return;
+        }
+        if let TSModuleReference::ExternalModuleReference(reference) = &mut decl.module_reference {
+            self.rewrite_extensions(&mut reference.expression, ctx);
+        }
+    }
 }
@@ -261,5 +261,109 @@ export interface TypeScriptBindingOptions {
    * @default false
    */
   rewriteImportExtensions?: 'rewrite' | 'remove' | boolean
+  /**
+   * Add `export {}` to files whose imports and exports are all removed,
+   * so they are still treated as modules.
+   *
+   * @default true
+   */
+  addEsmMarker?: boolean
+  /**
+   * Define class fields as class fields. When disabled, instance fields are initialized
+   * in the constructor instead.
+   *
+   * @default true
+   */
+  useDefineForClassFields?: boolean
+  /**
+   * Replace `const enum` member accesses with their constant values.
+   *
+   * @default false
+   */
+  optimizeConstEnums?: boolean
+  /**
+   * Transform `const enum` declarations the same as regular enums.
+   *
+   * @default false
+   */
+  preserveConstEnums?: boolean
+  /**
+   * Decides what happens to value imports which are only used as types.
+   *
+   * @default 'remove'
+   */
+  importsNotUsedAsValues?: 'remove' | 'preserve' | 'error'
+  /**
+   * Report constructs which can't be transformed correctly one file at a time.
+   *
+   * @default false
+   */
+  isolatedModules?: boolean
+  /**
+   * Report re-exported names without a `type` modifier which can't be resolved to a value.
+   *
+   * @default false
+   */
+  reportAmbiguousReExports?: boolean
+  /**
+   * Report kept imports which are only used as types, with `onlyRemoveTypeImports`.
+   *
+   * @default false
+   */
+  reportTypeOnlyImports?: boolean
+  /**
+   * Emit design-time type metadata for decorated classes, with `experimentalDecorators`.
+   *
+   * @default false
+   */
+  emitDecoratorMetadata?: boolean
+  /**
+   * Lower legacy decorators to `_decorate` helper calls.
+   *
+   * @default false
+   */
+  experimentalDecorators?: boolean
+  /**
+   * Import runtime helpers from `tslib` instead of defining them in every file.
+   *
+   * @default false
+   */
+  externalHelpers?: boolean
+  /**
+   * Lower auto-accessors to a private field with a getter and setter.
+   *
+   * @default false
+   */
+  lowerAutoAccessors?: boolean
+  /**
+   * Annotate the IIFEs emitted for enums with `/* #__PURE__ */`.
+   *
+   * @default false
+   */
+  annotatePureEnums?: boolean
+  /**
+   * Emit enums whose members are all constant as object literals.
+   *
+   * @default false
+   */
+  constantEnumsAsObjects?: boolean
+  /**
+   * Wrap enum objects in `Object.freeze(...)`.
+   *
+   * @default false
+   */
+  freezeEnums?: boolean
+  /**
+   * Decides the shape of the code emitted for enums.
+   *
+   * @default 'compact'
+   */
+  enumOutputStyle?: 'compact' | 'classic'
+  /**
+   * Replace the function called to load external modules in `import foo = require("foo")`.
+   *
+   * @default 'require'
+   */
+  requireName?: string
 }
 
@@ -3,8 +3,8 @@ use std::path::PathBuf;
 use napi::Either;
 use napi_derive::napi;
 use oxc_transformer::{
-    ArrowFunctionsOptions, ES2015Options, ReactJsxRuntime, ReactOptions, ReactRefreshOptions,
-    RewriteExtensionsMode, TypeScriptOptions,
+    ArrowFunctionsOptions, ES2015Options, EnumOutputStyle, ImportsNotUsedAsValues, ReactJsxRuntime,
+    ReactOptions, ReactRefreshOptions, RewriteExtensionsMode, TypeScriptOptions,
 };
 
 #[napi(object)]
@@ -33,6 +33,78 @@ pub struct TypeScriptBindingOptions {
     /// @default false
     #[napi(ts_type = "'rewrite' | 'remove' | boolean")]
     pub rewrite_import_extensions: Option<Either<bool, String>>,
+    /// Add `export {}` to files whose imports and exports are all removed,
+    /// so they are still treated as modules.
+    ///
+    /// @default true
+    pub add_esm_marker: Option<bool>,
+    /// Define class fields as class fields. When disabled, instance fields are initialized
+    /// in the constructor instead.
+    ///
+    /// @default true
+    pub use_define_for_class_fields: Option<bool>,
+    /// Replace `const enum` member accesses with their constant values.
+    ///
+    /// @default false
+    pub optimize_const_enums: Option<bool>,
+    /// Transform `const enum` declarations the same as regular enums.
+    ///
+    /// @default false
+    pub preserve_const_enums: Option<bool>,
+    /// Decides what happens to value imports which are only used as types.
+    ///
+    /// @default 'remove'
+    #[napi(ts_type = "'remove' | 'preserve' | 'error'")]
+    pub imports_not_used_as_values: Option<String>,
+    /// Report constructs which can't be transformed correctly one file at a time.
+    ///
+    /// @default false
+    pub isolated_modules: Option<bool>,
+    /// Report re-exported names without a `type` modifier which can't be resolved to a value.
+    ///
+    /// @default false
+    pub report_ambiguous_re_exports: Option<bool>,
+    /// Report kept imports which are only used as types, with `onlyRemoveTypeImports`.
+    ///
+    /// @default false
+    pub report_type_only_imports: Option<bool>,
+    /// Emit design-time type metadata for decorated classes, with `experimentalDecorators`.
+    ///
+    /// @default false
+    pub emit_decorator_metadata: Option<bool>,
+    /// Lower legacy decorators to `_decorate` helper calls.
+    ///
+    /// @default false
+    pub experimental_decorators: Option<bool>,
+    /// Import runtime helpers from `tslib` instead of defining them in every file.
+    ///
+    /// @default false
+    pub external_helpers: Option<bool>,
+    /// Lower auto-accessors to a private field with a getter and setter.
+    ///
+    /// @default false
+    pub lower_auto_accessors: Option<bool>,
+    /// Annotate the IIFEs emitted for enums with `/* #__PURE__ */`.
+    ///
+    /// @default false
+    pub annotate_pure_enums: Option<bool>,
+    /// Emit enums whose members are all constant as object literals.
+    ///
+    /// @default false
+    pub constant_enums_as_objects: Option<bool>,
+    /// Wrap enum objects in `Object.freeze(...)`.
+    ///
+    /// @default false
+    pub freeze_enums: Option<bool>,
+    /// Decides the shape of the code emitted for enums.
+    ///
+    /// @default 'compact'
+    #[napi(ts_type = "'compact' | 'classic'")]
+    pub enum_output_style: Option<String>,
+    /// Replace the function called to load external modules in `import foo = require("foo")`.
+    ///
+    /// @default 'require'
+    pub require_name: Option<String>,
 }
 
 impl From<TypeScriptBindingOptions> for TypeScriptOptions {
@@ -44,9 +116,44 @@ impl From<TypeScriptBindingOptions> for TypeScriptOptions {
             only_remove_type_imports: options
                 .only_remove_type_imports
                 .unwrap_or(ops.only_remove_type_imports),
+            add_esm_marker: options.add_esm_marker.unwrap_or(ops.add_esm_marker),
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
-            optimize_const_enums: false,
+            use_define_for_class_fields: options
+                .use_define_for_class_fields
+                .unwrap_or(ops.use_define_for_class_fields),
+            optimize_const_enums: options.optimize_const_enums.unwrap_or(ops.optimize_const_enums),
+            preserve_const_enums: options.preserve_const_enums.unwrap_or(ops.preserve_const_enums),
+            imports_not_used_as_values: match options.imports_not_used_as_values.as_deref() {
+                Some("preserve") => ImportsNotUsedAsValues::Preserve,
+                Some("error") => ImportsNotUsedAsValues::Error,
+                /* "remove" */ _ => ImportsNotUsedAsValues::Remove,
+            },
+            isolated_modules: options.isolated_modules.unwrap_or(ops.isolated_modules),
+            report_ambiguous_re_exports: options
+                .report_ambiguous_re_exports
+                .unwrap_or(ops.report_ambiguous_re_exports),
+            report_type_only_imports: options
+                .report_type_only_imports
+                .unwrap_or(ops.report_type_only_imports),
+            emit_decorator_metadata: options
+                .emit_decorator_metadata
+                .unwrap_or(ops.emit_decorator_metadata),
+            experimental_decorators: options
+                .experimental_decorators
+                .unwrap_or(ops.experimental_decorators),
+            external_helpers: options.external_helpers.unwrap_or(ops.external_helpers),
+            lower_auto_accessors: options.lower_auto_accessors.unwrap_or(ops.lower_auto_accessors),
+            annotate_pure_enums: options.annotate_pure_enums.unwrap_or(ops.annotate_pure_enums),
+            constant_enums_as_objects: options
+                .constant_enums_as_objects
+                .unwrap_or(ops.constant_enums_as_objects),
+            freeze_enums: options.freeze_enums.unwrap_or(ops.freeze_enums),
+            enum_output_style: match options.enum_output_style.as_deref() {
+                Some("classic") => EnumOutputStyle::Classic,
+                /* "compact" */ _ => EnumOutputStyle::Compact,
+            },
+            require_name: options.require_name.map(Into::into).unwrap_or(ops.require_name),
             rewrite_import_extensions: options.rewrite_import_extensions.and_then(|value| {
                 match value {
                     Either::A(v) => {
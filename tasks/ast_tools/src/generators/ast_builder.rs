@@ -231,6 +231,7 @@ fn default_init_field(field: &FieldDef) -> bool {
             field!(symbol_id: Cell<Option<SymbolId>>),
             field!(reference_id: Cell<Option<ReferenceId>>),
             field!(reference_flags: ReferenceFlags),
+            field!(pure: bool),
         ]);
     }
 
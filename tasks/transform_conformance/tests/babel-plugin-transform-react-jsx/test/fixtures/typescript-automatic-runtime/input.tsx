@@ -0,0 +1,16 @@
+/** @jsxImportSource preact */
+import type { ComponentChildren } from "preact";
+
+export function List({ items }: { items: string[] }): ComponentChildren {
+  return (
+    <ul>
+      {items.map((item) => (
+        <li key={item}>{item}</li>
+      ))}
+      <li>first</li>
+      <li>second</li>
+    </ul>
+  );
+}
+
+export const Empty = () => <></>;
@@ -0,0 +1,5 @@
+import { jsx as _jsx, jsxs as _jsxs, Fragment as _Fragment } from "preact/jsx-runtime";
+export function List({ items }) {
+	return _jsxs("ul", { children: [items.map((item) => _jsx("li", { children: item }, item)), _jsx("li", { children: "first" }), _jsx("li", { children: "second" })] });
+}
+export const Empty = () => _jsx(_Fragment, {});
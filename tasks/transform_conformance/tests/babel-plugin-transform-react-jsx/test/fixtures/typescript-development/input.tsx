@@ -0,0 +1,12 @@
+interface Props {
+  title: string;
+}
+
+export function Card({ title }: Props) {
+  return (
+    <section>
+      <h1 key="title">{title as string}</h1>
+      <p>body</p>
+    </section>
+  );
+}
@@ -0,0 +1,17 @@
+var _jsxFileName = "<CWD>/tests/babel-plugin-transform-react-jsx/test/fixtures/typescript-development/input.tsx";
+import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+export function Card({ title }) {
+	return _jsxDEV("section", { children: [_jsxDEV("h1", { children: title }, "title", false, {
+		fileName: _jsxFileName,
+		lineNumber: 8,
+		columnNumber: 7
+	}, this), _jsxDEV("p", { children: "body" }, void 0, false, {
+		fileName: _jsxFileName,
+		lineNumber: 9,
+		columnNumber: 7
+	}, this)] }, void 0, true, {
+		fileName: _jsxFileName,
+		lineNumber: 7,
+		columnNumber: 5
+	}, this);
+}
@@ -0,0 +1,3 @@
+import type { Theme } from "@emotion/react";
+
+export const Title = ({ theme }: { theme: Theme }) => <h1 css={{ color: theme.color }}>Title</h1>;
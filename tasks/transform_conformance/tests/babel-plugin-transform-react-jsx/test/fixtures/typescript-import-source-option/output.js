@@ -0,0 +1,5 @@
+import { jsx as _jsx } from "@emotion/react/jsx-runtime";
+export const Title = ({ theme }) => _jsx("h1", {
+	css: { color: theme.color },
+	children: "Title"
+});
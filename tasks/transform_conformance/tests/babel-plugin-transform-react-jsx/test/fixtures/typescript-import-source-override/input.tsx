@@ -0,0 +1,4 @@
+/** @jsxImportSource preact */
+import type { ComponentChildren } from "preact";
+
+export const Title = ({ children }: { children: ComponentChildren }) => <h1>{children}</h1>;
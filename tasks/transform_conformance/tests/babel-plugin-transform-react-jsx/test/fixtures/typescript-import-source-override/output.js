@@ -0,0 +1,2 @@
+import { jsx as _jsx } from "preact/jsx-runtime";
+export const Title = ({ children }) => _jsx("h1", { children });
@@ -0,0 +1,10 @@
+/** @jsx h */
+/** @jsxFrag Fragment */
+import { h, Fragment } from "preact";
+import type { VNode } from "preact";
+
+export const App = (): VNode => (
+  <>
+    <div class="app" />
+  </>
+);
@@ -0,0 +1,2 @@
+import { h, Fragment } from "preact";
+export const App = () => h(Fragment, null, h("div", { class: "app" }));
@@ -0,0 +1,8 @@
+import { h, Fragment } from "preact";
+import type { VNode } from "preact";
+
+export const App = (): VNode => (
+  <>
+    <div class="app" />
+  </>
+);
@@ -0,0 +1,12 @@
+const a = x as number;
+const b = { y: 1 } satisfies Record<string, number>;
+const c = ((x, y) as any).length;
+const d = (f as any)();
+const e = (x + 1) as number * 2;
+const g = (async () => {}) as unknown as Promise<void>;
+(obj as any).prop = 1;
+const h = new (Foo as any)();
+const k = () => ({} as Options);
+const l = () => ({ m: 1 }) satisfies Options;
+(function () {} as any)();
+({ n: 1 } as any).n;
@@ -0,0 +1,12 @@
+const a = x;
+const b = { y: 1 };
+const c = (x, y).length;
+const d = f();
+const e = (x + 1) * 2;
+const g = async () => {};
+obj.prop = 1;
+const h = new Foo();
+const k = () => ({});
+const l = () => ({ m: 1 });
+(function() {})();
+({ n: 1 }).n;
@@ -0,0 +1,23 @@
+export abstract class A<T> extends B implements I {
+  [key: string]: any;
+  abstract x: number;
+  abstract get y(): number;
+  abstract set y(v: number);
+  abstract m(): void;
+  protected abstract n?(a: T): void;
+  abstract accessor z: number;
+  static w = 1;
+  q = 2;
+  concrete() {
+    return 1;
+  }
+  get c() {
+    return 1;
+  }
+}
+
+export default abstract class E {
+  static [key: string]: number;
+  abstract f(): void;
+  g() {}
+}
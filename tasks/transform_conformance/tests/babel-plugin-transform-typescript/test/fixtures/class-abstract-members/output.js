@@ -0,0 +1,13 @@
+export class A extends B {
+	static w = 1;
+	q = 2;
+	concrete() {
+		return 1;
+	}
+	get c() {
+		return 1;
+	}
+}
+export default class E {
+	g() {}
+}
@@ -0,0 +1,11 @@
+class Base {
+  constructor(readonly id: number) {}
+}
+
+class Point extends Base {
+  constructor(id: number, private x = 0, protected y: number = x, public readonly label?: string) {
+    console.log("before");
+    super(id);
+    console.log(this.x, this.y);
+  }
+}
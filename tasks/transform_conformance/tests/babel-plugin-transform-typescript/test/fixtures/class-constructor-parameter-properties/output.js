@@ -0,0 +1,15 @@
+class Base {
+	constructor(id) {
+		this.id = id;
+	}
+}
+class Point extends Base {
+	constructor(id, x = 0, y = x, label) {
+		console.log("before");
+		super(id);
+		this.x = x;
+		this.y = y;
+		this.label = label;
+		console.log(this.x, this.y);
+	}
+}
@@ -0,0 +1,30 @@
+class A {
+  x = 1;
+  y: number;
+  "a-b": string = "ab";
+  0 = 0;
+  static s = 1;
+  #p = 1;
+  [key] = 1;
+  declare d: number;
+  m() {}
+}
+
+class B extends A {
+  z = () => this.x;
+  constructor(public a: number) {
+    super();
+    console.log(this.z);
+  }
+}
+
+class C extends A {
+  z = 2;
+}
+
+class D {
+  constructor(private a: number) {
+    console.log(a);
+  }
+  x = this.a;
+}
@@ -0,0 +1,32 @@
+class A {
+	constructor() {
+		this.x = 1;
+		this["a-b"] = "ab";
+		this[0] = 0;
+	}
+	static s = 1;
+	#p = 1;
+	[key] = 1;
+	m() {}
+}
+class B extends A {
+	constructor(a) {
+		super();
+		this.a = a;
+		this.z = () => this.x;
+		console.log(this.z);
+	}
+}
+class C extends A {
+	constructor(..._args) {
+		super(..._args);
+		this.z = 2;
+	}
+}
+class D {
+	constructor(a) {
+		this.a = a;
+		this.x = this.a;
+		console.log(a);
+	}
+}
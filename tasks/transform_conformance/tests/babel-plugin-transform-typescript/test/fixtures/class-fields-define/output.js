@@ -0,0 +1,28 @@
+class A {
+	x = 1;
+	y;
+	"a-b" = "ab";
+	0 = 0;
+	static s = 1;
+	#p = 1;
+	[key] = 1;
+	m() {}
+}
+class B extends A {
+	z = () => this.x;
+	constructor(a) {
+		super();
+		this.a = a;
+		console.log(this.z);
+	}
+}
+class C extends A {
+	z = 2;
+}
+class D {
+	constructor(a) {
+		this.a = a;
+		console.log(a);
+	}
+	x = this.a;
+}
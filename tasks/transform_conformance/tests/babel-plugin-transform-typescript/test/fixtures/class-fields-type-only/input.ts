@@ -0,0 +1,18 @@
+class A {
+  x: number;
+  y!: string;
+  z = 1;
+  w;
+  declare d: number;
+  static s: number;
+  #p: number;
+  [key]: number;
+}
+
+class B extends A {
+  b: boolean;
+  constructor() {
+    super();
+    console.log(this.b);
+  }
+}
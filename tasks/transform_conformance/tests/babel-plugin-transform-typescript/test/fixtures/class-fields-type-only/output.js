@@ -0,0 +1,18 @@
+class A {
+	constructor() {
+		this.x = void 0;
+		this.y = void 0;
+	}
+	z = 1;
+	w;
+	static s = void 0;
+	#p;
+	[key];
+}
+class B extends A {
+	constructor() {
+		super();
+		this.b = void 0;
+		console.log(this.b);
+	}
+}
@@ -0,0 +1,7 @@
+class C {
+  m?(): void {}
+  x?: number;
+  y?: number = 1;
+  static z?: string;
+  [Symbol.iterator]?() {}
+}
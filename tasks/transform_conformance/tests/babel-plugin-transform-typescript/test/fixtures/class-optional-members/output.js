@@ -0,0 +1,9 @@
+class C {
+	constructor() {
+		this.x = void 0;
+	}
+	m() {}
+	y = 1;
+	static z = void 0;
+	[Symbol.iterator]() {}
+}
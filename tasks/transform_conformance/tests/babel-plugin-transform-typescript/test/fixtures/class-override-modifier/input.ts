@@ -0,0 +1,13 @@
+declare class B {
+  m(): void;
+  x: number;
+}
+
+class C extends B {
+  override m() {}
+  override x = 1;
+  static override s() {}
+  constructor(protected override readonly y: number) {
+    super();
+  }
+}
@@ -0,0 +1,9 @@
+class C extends B {
+	m() {}
+	x = 1;
+	static s() {}
+	constructor(y) {
+		super();
+		this.y = y;
+	}
+}
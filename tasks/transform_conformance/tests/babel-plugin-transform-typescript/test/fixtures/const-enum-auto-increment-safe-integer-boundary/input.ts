@@ -0,0 +1,11 @@
+const enum Safe {
+  a = 2 ** 53 - 1,
+  b,
+}
+
+const enum Unsafe {
+  a = 2 ** 53,
+  b,
+}
+
+console.log(Safe.a, Safe.b, Unsafe.a, Unsafe.b);
@@ -0,0 +1,6 @@
+var Unsafe = function(Unsafe) {
+	Unsafe[Unsafe["a"] = 9007199254740992] = "a";
+	Unsafe[Unsafe["b"] = 1 + Unsafe["a"]] = "b";
+	return Unsafe;
+}(Unsafe || {});
+console.log(9007199254740991, 9007199254740992, Unsafe.a, Unsafe.b);
@@ -0,0 +1,11 @@
+declare const enum Flags {
+  A = 1,
+  B = compute(),
+}
+
+declare function compute(): number;
+
+Flags.B;
+Flags.C;
+Flags[key];
+const flags = Flags;
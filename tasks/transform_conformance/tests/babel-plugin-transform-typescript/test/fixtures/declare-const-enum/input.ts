@@ -0,0 +1,7 @@
+declare const enum Direction {
+  Up = 1,
+  Down,
+  Left = "left",
+}
+
+console.log(Direction.Up, Direction.Down, Direction["Left"]);
@@ -0,0 +1,18 @@
+export {};
+
+declare global {
+  interface Window {
+    app: App;
+  }
+  var __DEV__: boolean;
+  enum Mode {
+    A,
+  }
+  namespace NodeJS {
+    interface Global {}
+  }
+  function greet(): void;
+  class Foo {}
+}
+
+window.app.start();
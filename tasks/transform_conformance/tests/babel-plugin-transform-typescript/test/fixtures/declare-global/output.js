@@ -0,0 +1,2 @@
+window.app.start();
+export {};
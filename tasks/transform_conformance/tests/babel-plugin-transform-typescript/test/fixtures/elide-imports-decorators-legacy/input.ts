@@ -0,0 +1,15 @@
+import { Injectable } from "lib";
+import { Log } from "./log";
+import * as ns from "./ns";
+import { param } from "./param";
+import type { Options } from "./options";
+import { Unused } from "./unused";
+
+@Injectable()
+export class Service {
+  @Log
+  run(@param options: Options) {}
+
+  @ns.decorate()
+  field: Unused;
+}
@@ -0,0 +1,22 @@
+function _decorate(decorators, target, key, desc) {
+	var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+	if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+	return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _param(paramIndex, decorator) {
+	return function(target, key) {
+		decorator(target, key, paramIndex);
+	};
+}
+import { Injectable } from "lib";
+import { Log } from "./log";
+import * as ns from "./ns";
+import { param } from "./param";
+export let Service = class Service {
+	run(options) {}
+	field;
+};
+_decorate([Log, _param(0, param)], Service.prototype, "run", null);
+_decorate([ns.decorate()], Service.prototype, "field", void 0);
+Service = _decorate([Injectable()], Service);
@@ -0,0 +1,7 @@
+import { Injectable } from "lib";
+import { Log } from "./log";
+import * as ns from "./ns";
+export @Injectable() class Service {
+	@Log run(options) {}
+	@ns.decorate() field;
+}
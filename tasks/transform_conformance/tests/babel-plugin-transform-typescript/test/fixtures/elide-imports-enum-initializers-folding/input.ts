@@ -0,0 +1,21 @@
+import { VALUE } from "lib";
+import { EXPORTED } from "./exported";
+import * as consts from "./consts";
+import { INLINED } from "./inlined";
+import { Unused } from "./unused";
+
+enum E {
+  A = VALUE,
+  B = consts.B,
+}
+
+export enum Exported {
+  A = EXPORTED + 1,
+}
+
+const enum Inlined {
+  A = INLINED,
+  B = 1,
+}
+
+let x: Unused = Inlined.B;
@@ -0,0 +1,19 @@
+import { VALUE } from "lib";
+import { EXPORTED } from "./exported";
+import * as consts from "./consts";
+import { INLINED } from "./inlined";
+var E = function(E) {
+	E[E["A"] = VALUE] = "A";
+	E[E["B"] = consts.B] = "B";
+	return E;
+}(E || {});
+export let Exported = function(Exported) {
+	Exported[Exported["A"] = EXPORTED + 1] = "A";
+	return Exported;
+}({});
+var Inlined = function(Inlined) {
+	Inlined[Inlined["A"] = INLINED] = "A";
+	Inlined[Inlined["B"] = 1] = "B";
+	return Inlined;
+}(Inlined || {});
+let x = Inlined.B;
@@ -0,0 +1,16 @@
+import { Button } from "./button";
+import * as Foo from "./foo";
+import { Bar } from "./bar";
+import { handler } from "./handler";
+import { props } from "./props";
+import { Child } from "./child";
+import type { Props } from "./types";
+import { Unused } from "./unused";
+
+export const App = (_: Props) => (
+  <>
+    <Button />
+    <Foo.Bar onClick={handler} {...props} />
+    <Bar.Baz.Qux>{Child}</Bar.Baz.Qux>
+  </>
+);
@@ -0,0 +1,11 @@
+import { Button } from "./button";
+import * as Foo from "./foo";
+import { Bar } from "./bar";
+import { handler } from "./handler";
+import { props } from "./props";
+import { Child } from "./child";
+export const App = (_) => <>
+    <Button />
+    <Foo.Bar onClick={handler} {...props} />
+    <Bar.Baz.Qux>{Child}</Bar.Baz.Qux>
+  </>;
@@ -0,0 +1,20 @@
+import { imported } from "./imported";
+import { nested } from "./nested";
+import { member } from "./member";
+import type { Type } from "./type";
+import { OnlyType } from "./only-type";
+
+namespace N {
+  export const x = imported();
+  let y: OnlyType;
+}
+
+namespace Outer.Inner {
+  export const z: Type = nested;
+}
+
+namespace WithEnum {
+  export enum E {
+    A = member,
+  }
+}
@@ -0,0 +1,23 @@
+import { imported } from "./imported";
+import { nested } from "./nested";
+import { member } from "./member";
+let N;
+(function(_N) {
+	const x = _N.x = imported();
+	let y;
+})(N || (N = {}));
+let Outer;
+(function(_Outer) {
+	let Inner;
+	(function(_Inner) {
+		const z = _Inner.z = nested;
+	})(Inner || (Inner = _Outer.Inner || (_Outer.Inner = {})));
+})(Outer || (Outer = {}));
+let WithEnum;
+(function(_WithEnum) {
+	let E = function(E) {
+		E[E["A"] = member] = "A";
+		return E;
+	}(_WithEnum.E || {});
+	_WithEnum.E = E;
+})(WithEnum || (WithEnum = {}));
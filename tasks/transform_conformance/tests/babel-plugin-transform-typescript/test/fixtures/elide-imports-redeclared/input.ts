@@ -0,0 +1,4 @@
+import { Merged } from "./merged";
+let Merged = 1;
+
+export { Merged };
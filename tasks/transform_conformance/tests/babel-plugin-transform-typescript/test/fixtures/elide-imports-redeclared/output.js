@@ -0,0 +1,2 @@
+let Merged = 1;
+export { Merged };
@@ -0,0 +1,16 @@
+import { Param } from "./param";
+import { Block } from "./block";
+import { Exported } from "./exported";
+
+function fn(Param: number) {
+  return Param;
+}
+
+{
+  let Block = 1;
+  console.log(Block);
+}
+
+export { Exported };
+
+let value: Param | Block;
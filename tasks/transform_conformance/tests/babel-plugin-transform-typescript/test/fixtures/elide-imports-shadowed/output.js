@@ -0,0 +1,10 @@
+import { Exported } from "./exported";
+function fn(Param) {
+	return Param;
+}
+{
+	let Block = 1;
+	console.log(Block);
+}
+export { Exported };
+let value;
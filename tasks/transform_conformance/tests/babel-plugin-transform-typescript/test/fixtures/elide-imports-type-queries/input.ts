@@ -0,0 +1,18 @@
+import { config } from "./config";
+import { handler } from "./handler";
+import { create } from "./create";
+import { Store, defaults } from "./store";
+
+type Config = typeof config;
+
+interface Handlers {
+  handle: typeof handler;
+  options(): typeof config.options;
+}
+
+function make(): typeof create {
+  return null as any;
+}
+
+const store: typeof Store = new Store();
+export const value: typeof defaults.value = 1;
@@ -0,0 +1,6 @@
+import { Store } from "./store";
+function make() {
+	return null;
+}
+const store = new Store();
+export const value = 1;
@@ -0,0 +1,10 @@
+class Foo {
+  @Prop() date: Date;
+  @Prop() record: Record<string, number>;
+  @Prop() partial: Partial<Foo>;
+  @Prop() element: JSX.Element;
+  @Prop() map: Map<string, number> | undefined;
+
+  @Method()
+  method(a: Readonly<Foo>, b: Uint8Array): PromiseLike<void> {}
+}
@@ -0,0 +1,23 @@
+function _decorate(decorators, target, key, desc) {
+	var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+	if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+	return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _metadata(metadataKey, metadataValue) {
+	if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(metadataKey, metadataValue);
+}
+class Foo {
+	date;
+	record;
+	partial;
+	element;
+	map;
+	method(a, b) {}
+}
+_decorate([Prop(), _metadata("design:type", typeof Date === "undefined" ? Object : Date)], Foo.prototype, "date", void 0);
+_decorate([Prop(), _metadata("design:type", typeof Record === "undefined" ? Object : Record)], Foo.prototype, "record", void 0);
+_decorate([Prop(), _metadata("design:type", typeof Partial === "undefined" ? Object : Partial)], Foo.prototype, "partial", void 0);
+_decorate([Prop(), _metadata("design:type", typeof JSX === "undefined" ? Object : JSX.Element)], Foo.prototype, "element", void 0);
+_decorate([Prop(), _metadata("design:type", typeof Map === "undefined" ? Object : Map)], Foo.prototype, "map", void 0);
+_decorate([Method(), _metadata("design:type", Function), _metadata("design:paramtypes", [typeof Readonly === "undefined" ? Object : Readonly, typeof Uint8Array === "undefined" ? Object : Uint8Array]), _metadata("design:returntype", typeof PromiseLike === "undefined" ? Object : PromiseLike)], Foo.prototype, "method", null);
@@ -0,0 +1,8 @@
+const Reflect = { metadata: () => () => {} };
+
+class Foo {
+  @dec
+  method(a: string): number {
+    return Reflect.metadata === undefined ? 0 : 1;
+  }
+}
@@ -0,0 +1,16 @@
+function _decorate(decorators, target, key, desc) {
+	var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+	if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+	return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _metadata(metadataKey, metadataValue) {
+	if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(metadataKey, metadataValue);
+}
+const Reflect = { metadata: () => () => {} };
+class Foo {
+	method(a) {
+		return Reflect.metadata === undefined ? 0 : 1;
+	}
+}
+_decorate([dec, _metadata("design:type", Function), _metadata("design:paramtypes", [String]), _metadata("design:returntype", Number)], Foo.prototype, "method", null);
@@ -0,0 +1,9 @@
+@Component
+class Foo {
+  constructor(name: string) {}
+
+  @Prop() count: number;
+
+  @Method()
+  method(a: boolean): string {}
+}
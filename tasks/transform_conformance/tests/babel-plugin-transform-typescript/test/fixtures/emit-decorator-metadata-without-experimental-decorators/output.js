@@ -0,0 +1,5 @@
+@Component class Foo {
+	constructor(name) {}
+	@Prop() count;
+	@Method() method(a) {}
+}
@@ -0,0 +1,51 @@
+import { Service } from "./service";
+import type { Options } from "./options";
+
+interface Shape {}
+type Alias = string;
+enum Kind { A }
+class Model {}
+
+@Component
+class Foo {
+  constructor(service: Service, @Inject() options: Options, ...rest: number[]) {}
+
+  @Prop() name: string;
+  @Prop() count: number | undefined;
+  @Prop() model: Model | null;
+  @Prop() union: string | number;
+  @Prop() shape: Shape;
+  @Prop() alias: Alias;
+  @Prop() kind: Kind;
+  @Prop() list: Model[];
+  @Prop() callback: () => void;
+  @Prop() date: Date;
+  @Prop() literal: "a" | "b";
+  @Prop() untyped;
+
+  @Method()
+  method(a: boolean, b?: bigint): symbol {
+    return Symbol();
+  }
+
+  @Method()
+  async load(): Promise<void> {}
+
+  @Method()
+  nothing() {}
+
+  @Accessor()
+  get value(): number {
+    return 1;
+  }
+
+  @Accessor()
+  set value(v: number) {}
+
+  undecorated(a: string) {}
+}
+
+class Bar<T> {
+  @Prop() generic: T;
+  method(@Inject() model: Model) {}
+}
@@ -0,0 +1,69 @@
+function _decorate(decorators, target, key, desc) {
+	var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+	if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+	return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _param(paramIndex, decorator) {
+	return function(target, key) {
+		decorator(target, key, paramIndex);
+	};
+}
+function _metadata(metadataKey, metadataValue) {
+	if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(metadataKey, metadataValue);
+}
+import { Service } from "./service";
+var Kind = function(Kind) {
+	Kind[Kind["A"] = 0] = "A";
+	return Kind;
+}(Kind || {});
+class Model {}
+let Foo = class Foo {
+	constructor(service, options, ...rest) {}
+	name;
+	count;
+	model;
+	union;
+	shape;
+	alias;
+	kind;
+	list;
+	callback;
+	date;
+	literal;
+	untyped;
+	method(a, b) {
+		return Symbol();
+	}
+	async load() {}
+	nothing() {}
+	get value() {
+		return 1;
+	}
+	set value(v) {}
+	undecorated(a) {}
+};
+_decorate([Prop(), _metadata("design:type", String)], Foo.prototype, "name", void 0);
+_decorate([Prop(), _metadata("design:type", Number)], Foo.prototype, "count", void 0);
+_decorate([Prop(), _metadata("design:type", Model)], Foo.prototype, "model", void 0);
+_decorate([Prop(), _metadata("design:type", Object)], Foo.prototype, "union", void 0);
+_decorate([Prop(), _metadata("design:type", Object)], Foo.prototype, "shape", void 0);
+_decorate([Prop(), _metadata("design:type", Object)], Foo.prototype, "alias", void 0);
+_decorate([Prop(), _metadata("design:type", Object)], Foo.prototype, "kind", void 0);
+_decorate([Prop(), _metadata("design:type", Array)], Foo.prototype, "list", void 0);
+_decorate([Prop(), _metadata("design:type", Function)], Foo.prototype, "callback", void 0);
+_decorate([Prop(), _metadata("design:type", typeof Date === "undefined" ? Object : Date)], Foo.prototype, "date", void 0);
+_decorate([Prop(), _metadata("design:type", String)], Foo.prototype, "literal", void 0);
+_decorate([Prop(), _metadata("design:type", Object)], Foo.prototype, "untyped", void 0);
+_decorate([Method(), _metadata("design:type", Function), _metadata("design:paramtypes", [Boolean, BigInt]), _metadata("design:returntype", Symbol)], Foo.prototype, "method", null);
+_decorate([Method(), _metadata("design:type", Function), _metadata("design:paramtypes", []), _metadata("design:returntype", typeof Promise === "undefined" ? Object : Promise)], Foo.prototype, "load", null);
+_decorate([Method(), _metadata("design:type", Function), _metadata("design:paramtypes", []), _metadata("design:returntype", void 0)], Foo.prototype, "nothing", null);
+_decorate([Accessor(), _metadata("design:type", Number)], Foo.prototype, "value", null);
+_decorate([Accessor(), _metadata("design:type", Number), _metadata("design:paramtypes", [Number])], Foo.prototype, "value", null);
+Foo = _decorate([Component, _param(1, Inject()), _metadata("design:paramtypes", [Service, Object, Number])], Foo);
+class Bar {
+	generic;
+	method(model) {}
+}
+_decorate([Prop(), _metadata("design:type", Object)], Bar.prototype, "generic", void 0);
+_decorate([_param(0, Inject()), _metadata("design:type", Function), _metadata("design:paramtypes", [Model]), _metadata("design:returntype", void 0)], Bar.prototype, "method", null);
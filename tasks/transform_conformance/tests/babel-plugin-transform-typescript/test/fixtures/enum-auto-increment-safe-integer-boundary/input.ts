@@ -0,0 +1,17 @@
+enum A {
+  a = 2 ** 53 - 1,
+  b,
+  c,
+}
+
+enum B {
+  a = 2 ** 53 + 2,
+  b,
+}
+
+enum C {
+  a = -(2 ** 53) - 1,
+  b,
+  c = -(2 ** 53) - 2,
+  d,
+}
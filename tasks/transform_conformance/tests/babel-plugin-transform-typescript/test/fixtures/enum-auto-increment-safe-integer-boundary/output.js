@@ -0,0 +1,18 @@
+var A = function(A) {
+	A[A["a"] = 9007199254740991] = "a";
+	A[A["b"] = 9007199254740992] = "b";
+	A[A["c"] = 1 + A["b"]] = "c";
+	return A;
+}(A || {});
+var B = function(B) {
+	B[B["a"] = 9007199254740994] = "a";
+	B[B["b"] = 1 + B["a"]] = "b";
+	return B;
+}(B || {});
+var C = function(C) {
+	C[C["a"] = -9007199254740992] = "a";
+	C[C["b"] = -9007199254740991] = "b";
+	C[C["c"] = -9007199254740994] = "c";
+	C[C["d"] = 1 + C["c"]] = "d";
+	return C;
+}(C || {});
@@ -0,0 +1,13 @@
+const outer = 2;
+
+function compute() {
+  return 1;
+}
+
+enum E {
+  A = 1,
+  B = A << 1,
+  C = compute(),
+  D = C | outer,
+  F = outer,
+}
@@ -0,0 +1,12 @@
+const outer = 2;
+function compute() {
+	return 1;
+}
+var E = function(E) {
+	E[E["A"] = 1] = "A";
+	E[E["B"] = 2] = "B";
+	E[E["C"] = compute()] = "C";
+	E[E["D"] = E["C"] | outer] = "D";
+	E[E["F"] = outer] = "F";
+	return E;
+}(E || {});
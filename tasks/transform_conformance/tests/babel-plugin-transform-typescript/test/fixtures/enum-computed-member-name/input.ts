@@ -0,0 +1,12 @@
+declare const key: string;
+
+enum Foo {
+  A,
+  [key] = 1,
+  B,
+  C = "c",
+}
+
+enum Bar {
+  [1] = 2,
+}
@@ -0,0 +1,18 @@
+function compute() {
+  return 1;
+}
+
+enum Foo {
+  A = 1 << 4,
+  B = A | 1,
+  C = 2 ** 3,
+  D = (5 + 3) % 3,
+  E = -A * 2,
+  F = ~7,
+  G = (-1 >>> 28) ^ 3,
+  H = 7 / 2,
+  I = compute(),
+  J,
+  K = 2,
+  L,
+}
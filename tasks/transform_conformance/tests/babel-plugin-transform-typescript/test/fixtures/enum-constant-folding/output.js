@@ -0,0 +1,18 @@
+function compute() {
+	return 1;
+}
+var Foo = function(Foo) {
+	Foo[Foo["A"] = 16] = "A";
+	Foo[Foo["B"] = 17] = "B";
+	Foo[Foo["C"] = 8] = "C";
+	Foo[Foo["D"] = 2] = "D";
+	Foo[Foo["E"] = -32] = "E";
+	Foo[Foo["F"] = -8] = "F";
+	Foo[Foo["G"] = 12] = "G";
+	Foo[Foo["H"] = 3.5] = "H";
+	Foo[Foo["I"] = compute()] = "I";
+	Foo[Foo["J"] = 1 + Foo["I"]] = "J";
+	Foo[Foo["K"] = 2] = "K";
+	Foo[Foo["L"] = 3] = "L";
+	return Foo;
+}(Foo || {});
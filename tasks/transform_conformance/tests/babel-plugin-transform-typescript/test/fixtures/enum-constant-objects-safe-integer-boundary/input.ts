@@ -0,0 +1,9 @@
+enum Safe {
+  a = 2 ** 53 - 1,
+  b,
+}
+
+enum Unsafe {
+  a = 2 ** 53,
+  b,
+}
@@ -0,0 +1,11 @@
+var Safe = {
+	a: 9007199254740991,
+	9007199254740991: "a",
+	b: 9007199254740992,
+	9007199254740992: "b"
+};
+var Unsafe = function(Unsafe) {
+	Unsafe[Unsafe["a"] = 9007199254740992] = "a";
+	Unsafe[Unsafe["b"] = 1 + Unsafe["a"]] = "b";
+	return Unsafe;
+}(Unsafe || {});
@@ -0,0 +1,46 @@
+enum Numeric {
+  A,
+  B = 5,
+  C,
+  D = -1,
+  E = 1 / 0,
+  F = 0.5,
+}
+
+export enum Strings {
+  A = "a",
+  "b-c" = `b${"c"}`,
+}
+
+enum Mixed {
+  A = 1,
+  B = "b",
+  C = A << 2,
+  D = Numeric.B,
+}
+
+enum NotConstant {
+  A = Math.random(),
+  B,
+}
+
+enum Merged {
+  A,
+}
+enum Merged {
+  B = 1,
+}
+
+enum WithNamespace {
+  A,
+}
+namespace WithNamespace {
+  export const b = 1;
+}
+
+function f() {
+  enum Local {
+    A,
+  }
+  return Local;
+}
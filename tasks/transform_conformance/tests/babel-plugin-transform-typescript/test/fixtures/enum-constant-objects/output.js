@@ -0,0 +1,54 @@
+var Numeric = {
+	A: 0,
+	0: "A",
+	B: 5,
+	5: "B",
+	C: 6,
+	6: "C",
+	D: -1,
+	"-1": "D",
+	E: Infinity,
+	"Infinity": "E",
+	F: 0.5,
+	0.5: "F"
+};
+export let Strings = {
+	A: "a",
+	"b-c": "bc"
+};
+var Mixed = {
+	A: 1,
+	1: "A",
+	B: "b",
+	C: 4,
+	4: "C",
+	D: 5,
+	5: "D"
+};
+var NotConstant = function(NotConstant) {
+	NotConstant[NotConstant["A"] = Math.random()] = "A";
+	NotConstant[NotConstant["B"] = 1 + NotConstant["A"]] = "B";
+	return NotConstant;
+}(NotConstant || {});
+var Merged = function(Merged) {
+	Merged[Merged["A"] = 0] = "A";
+	return Merged;
+}(Merged || {});
+Merged = function(Merged) {
+	Merged[Merged["B"] = 1] = "B";
+	return Merged;
+}(Merged || {});
+var WithNamespace = function(WithNamespace) {
+	WithNamespace[WithNamespace["A"] = 0] = "A";
+	return WithNamespace;
+}(WithNamespace || {});
+(function(_WithNamespace) {
+	const b = _WithNamespace.b = 1;
+})(WithNamespace || (WithNamespace = {}));
+function f() {
+	let Local = {
+		A: 0,
+		0: "A"
+	};
+	return Local;
+}
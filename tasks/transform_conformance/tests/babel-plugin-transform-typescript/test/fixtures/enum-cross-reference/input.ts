@@ -0,0 +1,20 @@
+enum Flags {
+  None = 0,
+  A = 1 << 0,
+  B = 1 << 1,
+}
+
+enum Mask {
+  Empty = Flags.None,
+  All = Flags.A | Flags.B,
+  Next,
+}
+
+enum Before {
+  Empty = After.None,
+  Next,
+}
+
+enum After {
+  None = 0,
+}
@@ -0,0 +1,21 @@
+var Flags = function(Flags) {
+	Flags[Flags["None"] = 0] = "None";
+	Flags[Flags["A"] = 1] = "A";
+	Flags[Flags["B"] = 2] = "B";
+	return Flags;
+}(Flags || {});
+var Mask = function(Mask) {
+	Mask[Mask["Empty"] = 0] = "Empty";
+	Mask[Mask["All"] = 3] = "All";
+	Mask[Mask["Next"] = 4] = "Next";
+	return Mask;
+}(Mask || {});
+var Before = function(Before) {
+	Before[Before["Empty"] = After.None] = "Empty";
+	Before[Before["Next"] = 1 + Before["Empty"]] = "Next";
+	return Before;
+}(Before || {});
+var After = function(After) {
+	After[After["None"] = 0] = "None";
+	return After;
+}(After || {});
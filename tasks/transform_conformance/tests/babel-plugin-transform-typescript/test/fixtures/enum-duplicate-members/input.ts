@@ -0,0 +1,6 @@
+enum E {
+  A,
+  B,
+  A,
+  "B" = 5,
+}
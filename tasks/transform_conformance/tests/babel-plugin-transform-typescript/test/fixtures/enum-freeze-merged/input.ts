@@ -0,0 +1,13 @@
+enum Merged {
+  A,
+}
+namespace Merged {
+  export const b = 1;
+}
+
+enum Twice {
+  A,
+}
+enum Twice {
+  B = 1,
+}
@@ -0,0 +1,15 @@
+var Merged = function(Merged) {
+	Merged[Merged["A"] = 0] = "A";
+	return Merged;
+}(Merged || {});
+(function(_Merged) {
+	const b = _Merged.b = 1;
+})(Merged || (Merged = {}));
+var Twice = function(Twice) {
+	Twice[Twice["A"] = 0] = "A";
+	return Twice;
+}(Twice || {});
+Twice = function(Twice) {
+	Twice[Twice["B"] = 1] = "B";
+	return Twice;
+}(Twice || {});
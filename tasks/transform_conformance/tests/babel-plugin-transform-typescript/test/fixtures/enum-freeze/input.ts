@@ -0,0 +1,16 @@
+enum Direction {
+  Up,
+  Down,
+}
+
+export enum Color {
+  Red = "red",
+  Green = "green",
+}
+
+function f() {
+  enum Computed {
+    A = Math.random(),
+  }
+  return Computed;
+}
@@ -0,0 +1,17 @@
+var Direction = Object.freeze(function(Direction) {
+	Direction[Direction["Up"] = 0] = "Up";
+	Direction[Direction["Down"] = 1] = "Down";
+	return Direction;
+}(Direction || {}));
+export let Color = Object.freeze(function(Color) {
+	Color["Red"] = "red";
+	Color["Green"] = "green";
+	return Color;
+}({}));
+function f() {
+	let Computed = Object.freeze(function(Computed) {
+		Computed[Computed["A"] = Math.random()] = "A";
+		return Computed;
+	}({}));
+	return Computed;
+}
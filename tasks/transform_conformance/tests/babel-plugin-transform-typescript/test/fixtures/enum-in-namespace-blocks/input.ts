@@ -0,0 +1,20 @@
+namespace NS {
+  export enum E {
+    A,
+  }
+}
+
+namespace NS {
+  export enum E {
+    B = 2,
+  }
+  enum Local {
+    X,
+  }
+}
+
+namespace NS.Inner {
+  export enum F {
+    C = NS.E.B,
+  }
+}
@@ -0,0 +1,29 @@
+let NS;
+(function(_NS) {
+	let E = function(E) {
+		E[E["A"] = 0] = "A";
+		return E;
+	}(_NS.E || {});
+	_NS.E = E;
+})(NS || (NS = {}));
+(function(_NS2) {
+	let E = function(E) {
+		E[E["B"] = 2] = "B";
+		return E;
+	}(_NS2.E || {});
+	_NS2.E = E;
+	let Local = function(Local) {
+		Local[Local["X"] = 0] = "X";
+		return Local;
+	}({});
+})(NS || (NS = {}));
+(function(_NS3) {
+	let Inner;
+	(function(_Inner) {
+		let F = function(F) {
+			F[F["C"] = NS.E.B] = "C";
+			return F;
+		}(_Inner.F || {});
+		_Inner.F = F;
+	})(Inner || (Inner = _NS3.Inner || (_NS3.Inner = {})));
+})(NS || (NS = {}));
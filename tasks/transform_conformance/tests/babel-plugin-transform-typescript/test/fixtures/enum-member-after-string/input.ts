@@ -0,0 +1,10 @@
+function getPrefix() {
+  return "prefix";
+}
+
+const prefix = getPrefix();
+
+enum Foo {
+  A = prefix + "-a",
+  B,
+}
@@ -0,0 +1,5 @@
+enum Routes {
+  Base = "/api",
+  Users = Base + "/users",
+  Page,
+}
@@ -0,0 +1,16 @@
+enum Foo {
+  Foo = 1,
+  Bar = Foo,
+  Baz = Foo + Bar,
+}
+
+enum Foo {
+  Foo = 2,
+  Qux = Foo * 10,
+}
+
+enum E {
+  E = Math.random(),
+  F = E,
+  G = E.toString(),
+}
@@ -0,0 +1,17 @@
+var Foo = function(Foo) {
+	Foo[Foo["Foo"] = 1] = "Foo";
+	Foo[Foo["Bar"] = 1] = "Bar";
+	Foo[Foo["Baz"] = 2] = "Baz";
+	return Foo;
+}(Foo || {});
+Foo = function(Foo) {
+	Foo[Foo["Foo"] = 2] = "Foo";
+	Foo[Foo["Qux"] = 20] = "Qux";
+	return Foo;
+}(Foo || {});
+var E = function(E) {
+	E[E["E"] = Math.random()] = "E";
+	E[E["F"] = E["E"]] = "F";
+	E[E["G"] = E["E"].toString()] = "G";
+	return E;
+}(E || {});
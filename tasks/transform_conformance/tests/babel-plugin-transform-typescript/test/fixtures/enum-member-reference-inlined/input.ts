@@ -0,0 +1,10 @@
+var x = 10;
+
+enum Foo {
+  a = 10,
+  b = a,
+  c = b + x,
+  "not ident" = 3,
+  d = Foo["not ident"] + x,
+  e = a.toString(),
+}
@@ -0,0 +1,10 @@
+var x = 10;
+var Foo = function(Foo) {
+	Foo[Foo["a"] = 10] = "a";
+	Foo[Foo["b"] = 10] = "b";
+	Foo[Foo["c"] = 10 + x] = "c";
+	Foo[Foo["not ident"] = 3] = "not ident";
+	Foo[Foo["d"] = 3 + x] = "d";
+	Foo[Foo["e"] = 10 .toString()] = "e";
+	return Foo;
+}(Foo || {});
@@ -0,0 +1,17 @@
+const A = 100;
+const C = 200;
+
+function compute() {
+  return 1;
+}
+
+enum Foo {
+  A = 1,
+  B = A * 2,
+  C = compute(),
+  D = C + A,
+  E = C.toFixed(),
+  "not ident" = 3,
+  F = Foo["not ident"] << 1,
+  G = A.toString(),
+}
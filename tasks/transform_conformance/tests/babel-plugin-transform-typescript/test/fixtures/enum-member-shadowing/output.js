@@ -0,0 +1,16 @@
+const A = 100;
+const C = 200;
+function compute() {
+	return 1;
+}
+var Foo = function(Foo) {
+	Foo[Foo["A"] = 1] = "A";
+	Foo[Foo["B"] = 2] = "B";
+	Foo[Foo["C"] = compute()] = "C";
+	Foo[Foo["D"] = Foo["C"] + Foo.A] = "D";
+	Foo[Foo["E"] = Foo["C"].toFixed()] = "E";
+	Foo[Foo["not ident"] = 3] = "not ident";
+	Foo[Foo["F"] = 6] = "F";
+	Foo[Foo["G"] = Foo.A.toString()] = "G";
+	return Foo;
+}(Foo || {});
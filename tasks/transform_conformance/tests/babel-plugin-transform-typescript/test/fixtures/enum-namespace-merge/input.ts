@@ -0,0 +1,19 @@
+enum A { X }
+namespace A {
+  export function helper() {}
+}
+
+namespace B {
+  export function helper() {}
+}
+enum B { X }
+
+export enum C { X }
+export namespace C {
+  export const y = 1;
+}
+
+export namespace D {
+  export const y = 1;
+}
+export enum D { X }
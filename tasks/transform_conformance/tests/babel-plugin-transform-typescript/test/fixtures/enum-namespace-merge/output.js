@@ -0,0 +1,32 @@
+var A = function(A) {
+	A[A["X"] = 0] = "X";
+	return A;
+}(A || {});
+(function(_A) {
+	function helper() {}
+	_A.helper = helper;
+})(A || (A = {}));
+let B;
+(function(_B) {
+	function helper() {}
+	_B.helper = helper;
+})(B || (B = {}));
+B = function(B) {
+	B[B["X"] = 0] = "X";
+	return B;
+}(B || {});
+export let C = function(C) {
+	C[C["X"] = 0] = "X";
+	return C;
+}({});
+(function(_C) {
+	const y = _C.y = 1;
+})(C || (C = {}));
+export let D;
+(function(_D) {
+	const y = _D.y = 1;
+})(D || (D = {}));
+D = function(D) {
+	D[D["X"] = 0] = "X";
+	return D;
+}(D || {});
@@ -0,0 +1,15 @@
+const E = "outer";
+
+function f(flag: boolean) {
+  if (flag) {
+    enum E {
+      A,
+    }
+    enum E {
+      B = A + 1,
+      C = [1, 2].map((n) => n * B)[0],
+    }
+    return E.B;
+  }
+  return E;
+}
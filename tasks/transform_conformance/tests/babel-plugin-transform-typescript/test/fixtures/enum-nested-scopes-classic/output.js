@@ -0,0 +1,15 @@
+const E = "outer";
+function f(flag) {
+	if (flag) {
+		let E;
+		(function(E) {
+			E[E["A"] = 0] = "A";
+		})(E || (E = {}));
+		(function(E) {
+			E[E["B"] = 1] = "B";
+			E[E["C"] = [1, 2].map((n) => n * E.B)[0]] = "C";
+		})(E || (E = {}));
+		return E.B;
+	}
+	return E;
+}
@@ -0,0 +1,24 @@
+const E = "outer";
+
+function outer() {
+  const A = 10;
+  function f() {
+    enum E {
+      A,
+      B = A + 1,
+      C = B * 2,
+    }
+    return E.A;
+  }
+  return f();
+}
+
+function g(flag: boolean) {
+  if (flag) {
+    enum E {
+      X = 1,
+    }
+    return E.X;
+  }
+  return E;
+}
@@ -0,0 +1,24 @@
+const E = "outer";
+function outer() {
+	const A = 10;
+	function f() {
+		let E = function(E) {
+			E[E["A"] = 0] = "A";
+			E[E["B"] = 1] = "B";
+			E[E["C"] = 2] = "C";
+			return E;
+		}({});
+		return E.A;
+	}
+	return f();
+}
+function g(flag) {
+	if (flag) {
+		let E = function(E) {
+			E[E["X"] = 1] = "X";
+			return E;
+		}({});
+		return E.X;
+	}
+	return E;
+}
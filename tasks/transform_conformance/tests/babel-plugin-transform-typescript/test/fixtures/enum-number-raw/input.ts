@@ -0,0 +1,9 @@
+enum Numbers {
+  a = -0,
+  b = 2 ** 32,
+  c = 2 ** 31 + 1,
+  d = 5 / 2,
+  e = 0.1 + 0.2,
+  f = 1e21,
+  g = -1 / 0,
+}
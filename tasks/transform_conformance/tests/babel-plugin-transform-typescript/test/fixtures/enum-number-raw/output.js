@@ -0,0 +1,10 @@
+var Numbers = function(Numbers) {
+	Numbers[Numbers["a"] = -0] = "a";
+	Numbers[Numbers["b"] = 4294967296] = "b";
+	Numbers[Numbers["c"] = 2147483649] = "c";
+	Numbers[Numbers["d"] = 2.5] = "d";
+	Numbers[Numbers["e"] = 0.30000000000000004] = "e";
+	Numbers[Numbers["f"] = 1e+21] = "f";
+	Numbers[Numbers["g"] = -Infinity] = "g";
+	return Numbers;
+}(Numbers || {});
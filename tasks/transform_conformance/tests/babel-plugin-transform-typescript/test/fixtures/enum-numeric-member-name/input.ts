@@ -0,0 +1,9 @@
+enum Strings {
+  0 = "zero",
+  1 = "one",
+}
+
+enum Numbers {
+  1 = 1,
+  0x10,
+}
@@ -0,0 +1,27 @@
+enum Color {
+  Red,
+  Green,
+}
+
+enum Color {
+  Blue = 2,
+}
+
+export enum Direction {
+  Up = "up",
+  Down = "down",
+}
+
+enum Merged {
+  A,
+}
+namespace Merged {
+  export const b = 1;
+}
+
+function scoped() {
+  enum Local {
+    X = 1,
+  }
+  return Local.X;
+}
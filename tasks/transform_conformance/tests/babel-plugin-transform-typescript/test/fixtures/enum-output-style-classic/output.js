@@ -0,0 +1,27 @@
+var Color;
+(function(Color) {
+	Color[Color["Red"] = 0] = "Red";
+	Color[Color["Green"] = 1] = "Green";
+})(Color || (Color = {}));
+(function(Color) {
+	Color[Color["Blue"] = 2] = "Blue";
+})(Color || (Color = {}));
+export var Direction;
+(function(Direction) {
+	Direction["Up"] = "up";
+	Direction["Down"] = "down";
+})(Direction || (Direction = {}));
+var Merged;
+(function(Merged) {
+	Merged[Merged["A"] = 0] = "A";
+})(Merged || (Merged = {}));
+(function(_Merged) {
+	const b = _Merged.b = 1;
+})(Merged || (Merged = {}));
+function scoped() {
+	let Local;
+	(function(Local) {
+		Local[Local["X"] = 1] = "X";
+	})(Local || (Local = {}));
+	return Local.X;
+}
@@ -0,0 +1,28 @@
+var Color = function(Color) {
+	Color[Color["Red"] = 0] = "Red";
+	Color[Color["Green"] = 1] = "Green";
+	return Color;
+}(Color || {});
+Color = function(Color) {
+	Color[Color["Blue"] = 2] = "Blue";
+	return Color;
+}(Color || {});
+export let Direction = function(Direction) {
+	Direction["Up"] = "up";
+	Direction["Down"] = "down";
+	return Direction;
+}({});
+var Merged = function(Merged) {
+	Merged[Merged["A"] = 0] = "A";
+	return Merged;
+}(Merged || {});
+(function(_Merged) {
+	const b = _Merged.b = 1;
+})(Merged || (Merged = {}));
+function scoped() {
+	let Local = function(Local) {
+		Local[Local["X"] = 1] = "X";
+		return Local;
+	}({});
+	return Local.X;
+}
@@ -0,0 +1,14 @@
+function compute() {
+  return 1;
+}
+
+enum Color {
+  Red = 1,
+  RedAlias = Color.Red,
+  Next = Color["Red"] + 1,
+  "Light Red" = Color.Next << 2,
+  LightRedAlias = Color["Light Red"],
+  Dynamic = compute(),
+  DynamicNext = Color.Red + compute(),
+  DynamicLight = Color["Light Red"] * compute(),
+}
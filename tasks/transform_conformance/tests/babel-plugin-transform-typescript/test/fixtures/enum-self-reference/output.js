@@ -0,0 +1,14 @@
+function compute() {
+	return 1;
+}
+var Color = function(Color) {
+	Color[Color["Red"] = 1] = "Red";
+	Color[Color["RedAlias"] = 1] = "RedAlias";
+	Color[Color["Next"] = 2] = "Next";
+	Color[Color["Light Red"] = 8] = "Light Red";
+	Color[Color["LightRedAlias"] = 8] = "LightRedAlias";
+	Color[Color["Dynamic"] = compute()] = "Dynamic";
+	Color[Color["DynamicNext"] = 1 + compute()] = "DynamicNext";
+	Color[Color["DynamicLight"] = 8 * compute()] = "DynamicLight";
+	return Color;
+}(Color || {});
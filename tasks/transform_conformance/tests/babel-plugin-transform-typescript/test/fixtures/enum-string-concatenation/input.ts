@@ -0,0 +1,8 @@
+enum Routes {
+  Base = "/api",
+  Users = Base + "/users",
+  User = Routes.Users + "/" + 1,
+  Version = `v` + 2,
+  Page = 1,
+  NextPage,
+}
@@ -0,0 +1,9 @@
+var Routes = function(Routes) {
+	Routes["Base"] = "/api";
+	Routes["Users"] = "/api/users";
+	Routes["User"] = "/api/users/1";
+	Routes["Version"] = "v2";
+	Routes[Routes["Page"] = 1] = "Page";
+	Routes[Routes["NextPage"] = 2] = "NextPage";
+	return Routes;
+}(Routes || {});
@@ -0,0 +1,13 @@
+function getPrefix() {
+  return "prefix";
+}
+
+const prefix = getPrefix();
+
+enum Foo {
+  A = `${prefix}-a`,
+  B = prefix + "-b",
+  C = 1,
+  D = `d`,
+  E = `${C}-e`,
+}
@@ -0,0 +1,12 @@
+function getPrefix() {
+	return "prefix";
+}
+const prefix = getPrefix();
+var Foo = function(Foo) {
+	Foo["A"] = `${prefix}-a`;
+	Foo["B"] = prefix + "-b";
+	Foo[Foo["C"] = 1] = "C";
+	Foo["D"] = "d";
+	Foo["E"] = "1-e";
+	return Foo;
+}(Foo || {});
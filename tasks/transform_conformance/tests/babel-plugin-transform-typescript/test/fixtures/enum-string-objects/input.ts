@@ -0,0 +1,24 @@
+enum Direction {
+  Up = "up",
+  Down = "down",
+}
+
+export enum Quoted {
+  "kebab-case" = "kebab",
+  "with space" = `with ${"space"}`,
+  Plain = "plain",
+}
+
+enum Folded {
+  A = "a",
+  B = A + "b",
+  C = `${B}c`,
+}
+
+declare const suffix: string;
+enum NotFolded {
+  A = "a",
+  B = A + suffix,
+  C = suffix + NotFolded.A,
+  D = `${suffix}`,
+}
@@ -0,0 +1,21 @@
+var Direction = {
+	Up: "up",
+	Down: "down"
+};
+export let Quoted = {
+	"kebab-case": "kebab",
+	"with space": "with space",
+	Plain: "plain"
+};
+var Folded = {
+	A: "a",
+	B: "ab",
+	C: "abc"
+};
+var NotFolded = function(NotFolded) {
+	NotFolded["A"] = "a";
+	NotFolded["B"] = NotFolded.A + suffix;
+	NotFolded["C"] = suffix + "a";
+	NotFolded["D"] = `${suffix}`;
+	return NotFolded;
+}(NotFolded || {});
@@ -0,0 +1,20 @@
+enum A {
+  a = -1,
+  b,
+  c = ~b,
+  d,
+  e = +d,
+  f,
+}
+
+enum B {
+  a = -0,
+  b,
+}
+
+enum C {
+  a = 2 ** 53,
+  b,
+  c = 1e21,
+  d,
+}
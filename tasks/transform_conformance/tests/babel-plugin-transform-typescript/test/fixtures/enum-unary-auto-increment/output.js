@@ -0,0 +1,21 @@
+var A = function(A) {
+	A[A["a"] = -1] = "a";
+	A[A["b"] = 0] = "b";
+	A[A["c"] = -1] = "c";
+	A[A["d"] = 0] = "d";
+	A[A["e"] = 0] = "e";
+	A[A["f"] = 1] = "f";
+	return A;
+}(A || {});
+var B = function(B) {
+	B[B["a"] = -0] = "a";
+	B[B["b"] = 1] = "b";
+	return B;
+}(B || {});
+var C = function(C) {
+	C[C["a"] = 9007199254740992] = "a";
+	C[C["b"] = 1 + C["a"]] = "b";
+	C[C["c"] = 1e+21] = "c";
+	C[C["d"] = 1 + C["c"]] = "d";
+	return C;
+}(C || {});
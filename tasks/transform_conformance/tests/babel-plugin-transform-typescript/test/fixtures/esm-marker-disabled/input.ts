@@ -0,0 +1,6 @@
+import type { Foo } from "./foo";
+import { Bar } from "./bar";
+
+export type { Foo };
+
+let value: Bar = 1;
@@ -0,0 +1 @@
+let value = 1;
@@ -0,0 +1,4 @@
+import type { A } from "./a";
+export type { A };
+
+export const value: A = 1;
@@ -0,0 +1,4 @@
+import type { A } from "./a";
+import { type B } from "./b";
+
+let value: A | B;
@@ -0,0 +1,2 @@
+let value;
+export {};
@@ -0,0 +1,4 @@
+export type * as types from "./types";
+export * as values from "./values";
+export type * from "./more-types";
+export * from "./more-values";
@@ -0,0 +1,2 @@
+export * as values from "./values";
+export * from "./more-values";
@@ -0,0 +1,3 @@
+import x = require("x");
+
+export = x;
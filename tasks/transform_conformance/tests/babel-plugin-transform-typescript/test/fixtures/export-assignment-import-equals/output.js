@@ -0,0 +1,2 @@
+var x = require("x");
+module.exports = x;
@@ -0,0 +1,4 @@
+import x = require("x");
+import helpers = require("./helpers");
+
+export = { x, helper: helpers.helper };
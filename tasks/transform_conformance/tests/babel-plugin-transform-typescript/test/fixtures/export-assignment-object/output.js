@@ -0,0 +1,6 @@
+var x = require("x");
+var helpers = require("./helpers");
+module.exports = {
+	x,
+	helper: helpers.helper
+};
@@ -0,0 +1,2 @@
+function foo() {}
+module.exports = foo;
@@ -0,0 +1,5 @@
+export default function parse(input: string): number;
+export default function parse(input: number): number;
+export default function parse(input: any) {
+  return Number(input);
+}
@@ -0,0 +1,3 @@
+export default function parse(input) {
+	return Number(input);
+}
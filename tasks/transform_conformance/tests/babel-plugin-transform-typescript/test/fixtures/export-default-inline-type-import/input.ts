@@ -0,0 +1,4 @@
+import { type Foo, bar } from "./foo";
+
+export default Foo;
+export { bar };
@@ -0,0 +1,2 @@
+import { bar } from "./foo";
+export { bar };
@@ -0,0 +1,6 @@
+export { T as default, U as Other, Local, value };
+
+import type { T } from "./t";
+import { type U } from "./u";
+interface Local {}
+const value = 1;
@@ -0,0 +1,2 @@
+export { value };
+const value = 1;
@@ -0,0 +1,3 @@
+type Alias = string;
+
+export default Alias;
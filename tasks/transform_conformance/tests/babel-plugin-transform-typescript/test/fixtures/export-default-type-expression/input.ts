@@ -0,0 +1,5 @@
+namespace Types {
+  export type T = string;
+}
+
+export default (Types as unknown);
@@ -0,0 +1,5 @@
+import type { Foo } from "./foo";
+import { type Bar } from "./bar";
+import { Baz } from "./baz";
+
+export { Foo as default, Bar, Baz };
@@ -0,0 +1,2 @@
+import { Baz } from "./baz";
+export { Baz };
@@ -0,0 +1,6 @@
+interface Config {
+  debug: boolean;
+}
+const Config = { debug: false };
+
+export default Config;
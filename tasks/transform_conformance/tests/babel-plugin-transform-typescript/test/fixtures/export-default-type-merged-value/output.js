@@ -0,0 +1,2 @@
+const Config = { debug: false };
+export default Config;
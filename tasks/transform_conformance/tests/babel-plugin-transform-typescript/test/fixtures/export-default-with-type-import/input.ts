@@ -0,0 +1,5 @@
+import type { Foo } from "./foo";
+
+export default function create(): Foo {
+  return {};
+}
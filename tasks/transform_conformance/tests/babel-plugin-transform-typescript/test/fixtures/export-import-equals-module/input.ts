@@ -0,0 +1,7 @@
+namespace Utils {
+  export const version = 1;
+}
+
+export import version = Utils.version;
+
+console.log(version);
@@ -0,0 +1,6 @@
+let Utils;
+(function(_Utils) {
+	const version = _Utils.version = 1;
+})(Utils || (Utils = {}));
+export var version = Utils.version;
+console.log(version);
@@ -0,0 +1,8 @@
+namespace Utils {
+  export const version = 1;
+}
+
+export import version = Utils.version;
+export import fs = require("fs");
+
+console.log(version, fs);
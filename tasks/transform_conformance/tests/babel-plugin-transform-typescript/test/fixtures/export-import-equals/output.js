@@ -0,0 +1,7 @@
+let Utils;
+(function(_Utils) {
+	const version = _Utils.version = 1;
+})(Utils || (Utils = {}));
+var version = exports.version = Utils.version;
+var fs = exports.fs = require("fs");
+console.log(version, fs);
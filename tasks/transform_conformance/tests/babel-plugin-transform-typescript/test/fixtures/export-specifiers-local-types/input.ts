@@ -0,0 +1,19 @@
+interface Opts {}
+type Alias = string;
+namespace TypeNs {
+  export type X = 1;
+}
+namespace ValueNs {
+  export const y = 1;
+}
+import TypeEq = TypeNs.X;
+import ValueEq = ValueNs.y;
+enum Enum {
+  A,
+}
+const runtimeValue = 1;
+
+export { Opts, runtimeValue };
+export { Alias, TypeNs, TypeEq as TypeEqAlias };
+export { ValueEq, Enum };
+export { runtimeValue as TypeNs2 };
@@ -0,0 +1,13 @@
+let ValueNs;
+(function(_ValueNs) {
+	const y = _ValueNs.y = 1;
+})(ValueNs || (ValueNs = {}));
+var ValueEq = ValueNs.y;
+var Enum = function(Enum) {
+	Enum[Enum["A"] = 0] = "A";
+	return Enum;
+}(Enum || {});
+const runtimeValue = 1;
+export { runtimeValue };
+export { ValueEq, Enum };
+export { runtimeValue as TypeNs2 };
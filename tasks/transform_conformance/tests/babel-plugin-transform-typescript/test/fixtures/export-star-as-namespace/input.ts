@@ -0,0 +1,10 @@
+import type * as Types from "./types";
+
+interface Utils {}
+
+export * from "./all";
+export * as Utils from "./utils";
+export type * as Types from "./types";
+export type * from "./more-types";
+
+export const value: Types.Value = 1;
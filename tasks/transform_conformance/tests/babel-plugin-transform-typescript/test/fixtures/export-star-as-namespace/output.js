@@ -0,0 +1,3 @@
+export * from "./all";
+export * as Utils from "./utils";
+export const value = 1;
@@ -0,0 +1,15 @@
+type Foo = string;
+interface Bar {}
+namespace Baz {
+  export type X = 1;
+}
+
+export type { Foo } from "./foo";
+export { type Foo as Foo1 } from "./foo";
+export { type Foo as Foo2, bar } from "./foo";
+export { Foo as Foo3 } from "./other";
+export { a as Bar, Baz } from "./a";
+export * from "./all";
+export * as ns from "./ns";
+export type * from "./types";
+export type * as types from "./types";
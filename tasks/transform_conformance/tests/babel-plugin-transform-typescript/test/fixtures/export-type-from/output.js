@@ -0,0 +1,5 @@
+export { bar } from "./foo";
+export { Foo as Foo3 } from "./other";
+export { a as Bar, Baz } from "./a";
+export * from "./all";
+export * as ns from "./ns";
@@ -0,0 +1,24 @@
+function foo(a: string): string;
+function foo(a: number): number;
+function foo(a: any): any {
+  return a;
+}
+
+export function bar(a: string): void;
+export function bar(a: any) {}
+
+export default function baz(a: string): void;
+export default function baz(a: any) {}
+
+declare function qux(): void;
+function qux() {}
+
+class C {
+  constructor(a: string);
+  constructor(a: any) {}
+  m(a: string): void;
+  m(a: number): void;
+  m(a: any) {}
+  static s(): void;
+  static s() {}
+}
@@ -0,0 +1,11 @@
+function foo(a) {
+	return a;
+}
+export function bar(a) {}
+export default function baz(a) {}
+function qux() {}
+class C {
+	constructor(a) {}
+	m(a) {}
+	static s() {}
+}
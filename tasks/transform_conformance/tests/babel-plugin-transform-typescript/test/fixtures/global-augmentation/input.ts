@@ -0,0 +1,10 @@
+export {};
+
+global {
+  var __DEV__: boolean;
+  interface Window {
+    app: unknown;
+  }
+}
+
+console.log(__DEV__);
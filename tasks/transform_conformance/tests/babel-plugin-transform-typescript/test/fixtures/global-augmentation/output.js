@@ -0,0 +1,2 @@
+console.log(__DEV__);
+export {};
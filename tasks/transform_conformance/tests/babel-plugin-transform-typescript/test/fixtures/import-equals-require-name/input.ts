@@ -0,0 +1,4 @@
+import fs = require("fs");
+export import path = require("path");
+
+console.log(fs, path);
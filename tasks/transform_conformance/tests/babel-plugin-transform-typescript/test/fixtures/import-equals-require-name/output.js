@@ -0,0 +1,3 @@
+var fs = __require("fs");
+var path = exports.path = __require("path");
+console.log(fs, path);
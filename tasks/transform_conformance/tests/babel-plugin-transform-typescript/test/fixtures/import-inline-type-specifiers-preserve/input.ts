@@ -0,0 +1,11 @@
+import { type A } from "./all-inline";
+import { type B, type C } from "./all-inline-multiple";
+import { type D, e } from "./mixed-used";
+import { type F, g } from "./mixed-unused";
+import H, { type I } from "./default-used";
+import type { J } from "./statement";
+import type K from "./statement-default";
+import {} from "./empty";
+
+type T = A | B | C | D | F | I | J | K;
+console.log(e, H);
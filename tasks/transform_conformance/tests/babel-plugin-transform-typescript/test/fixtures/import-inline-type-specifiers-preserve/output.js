@@ -0,0 +1,7 @@
+import "./all-inline";
+import "./all-inline-multiple";
+import { e } from "./mixed-used";
+import "./mixed-unused";
+import H from "./default-used";
+import "./empty";
+console.log(e, H);
@@ -0,0 +1,4 @@
+import { e } from "./mixed-used";
+import H from "./default-used";
+import "./empty";
+console.log(e, H);
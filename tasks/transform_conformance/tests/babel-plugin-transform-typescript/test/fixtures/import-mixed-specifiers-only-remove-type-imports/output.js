@@ -0,0 +1,6 @@
+import a from "./a";
+import c, { e, g } from "./c";
+import { i } from "./h";
+import * as ns from "./ns";
+console.log(a, c, e, g, i, ns);
+let value;
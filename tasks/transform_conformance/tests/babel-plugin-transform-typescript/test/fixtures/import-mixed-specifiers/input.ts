@@ -0,0 +1,7 @@
+import a, { type B } from "./a";
+import c, { type D, e, type F, g } from "./c";
+import { type H, i } from "./h";
+import * as ns from "./ns";
+
+console.log(a, c, e, g, i, ns);
+let value: B | D | F | H;
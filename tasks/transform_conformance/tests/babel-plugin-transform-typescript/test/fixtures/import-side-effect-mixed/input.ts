@@ -0,0 +1,7 @@
+import "./polyfill";
+import { Type } from "./polyfill";
+import type { Other } from "./polyfill";
+import {} from "./empty";
+import "./styles.css";
+
+let value: Type | Other;
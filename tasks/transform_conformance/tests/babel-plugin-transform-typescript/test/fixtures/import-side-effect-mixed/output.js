@@ -0,0 +1,4 @@
+import "./polyfill";
+import "./empty";
+import "./styles.css";
+let value;
@@ -0,0 +1,3 @@
+import type { type A, B } from "./mod";
+
+export type T = A | B;
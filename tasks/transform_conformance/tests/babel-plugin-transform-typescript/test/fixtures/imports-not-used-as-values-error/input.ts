@@ -0,0 +1,13 @@
+import "./polyfill";
+import type { Config } from "./config";
+import { type Options } from "./options";
+import { Logger } from "./logger";
+import Default, { used, unused } from "./mixed";
+import * as types from "./types";
+
+const config: Config = {};
+const options: Options = {};
+let logger: Logger;
+let t: types.T;
+
+used();
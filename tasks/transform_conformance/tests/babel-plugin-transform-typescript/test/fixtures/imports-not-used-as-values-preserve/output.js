@@ -0,0 +1,10 @@
+import "./polyfill";
+import "./options";
+import "./logger";
+import { used } from "./mixed";
+import "./types";
+const config = {};
+const options = {};
+let logger;
+let t;
+used();
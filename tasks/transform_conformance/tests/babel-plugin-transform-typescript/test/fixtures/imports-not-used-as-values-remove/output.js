@@ -0,0 +1,7 @@
+import "./polyfill";
+import { used } from "./mixed";
+const config = {};
+const options = {};
+let logger;
+let t;
+used();
@@ -0,0 +1,12 @@
+declare function f<T>(x?: T): T;
+declare const obj: { m<T>(): T };
+declare class Box<T> {}
+
+const g = f<number>;
+const h = obj.m<string>;
+f<number>(1);
+(f<number>)(2);
+f<number>?.(3);
+new Box<number>();
+const ctor = Box<string>;
+const nested = f<typeof g<number>>;
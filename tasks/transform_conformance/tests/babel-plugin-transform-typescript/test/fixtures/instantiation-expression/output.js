@@ -0,0 +1,8 @@
+const g = f;
+const h = obj.m;
+f(1);
+f(2);
+f?.(3);
+new Box();
+const ctor = Box;
+const nested = f;
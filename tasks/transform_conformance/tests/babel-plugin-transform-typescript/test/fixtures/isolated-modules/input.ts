@@ -0,0 +1,17 @@
+import { Value, Type } from "./a";
+
+interface Local {}
+type Alias = string;
+
+export { Value, Type, Local, Alias };
+export type { Local as LocalType };
+
+export const enum Direction {
+  Up,
+  Down,
+}
+
+const enum Internal {
+  A,
+}
+console.log(Value, Internal.A);
@@ -0,0 +1,7 @@
+const C = @dec class {
+  @dec m(@dec a) {}
+};
+
+const D = class {
+  @dec x;
+};
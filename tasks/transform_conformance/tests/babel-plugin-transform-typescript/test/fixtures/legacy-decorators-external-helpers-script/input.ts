@@ -0,0 +1,11 @@
+"use strict";
+
+const { Injectable } = require("lib");
+
+@Injectable()
+class Service {
+  @Injectable()
+  run() {}
+}
+
+module.exports = Service;
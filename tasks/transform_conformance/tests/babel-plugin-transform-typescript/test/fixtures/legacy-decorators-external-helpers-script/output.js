@@ -0,0 +1,9 @@
+"use strict";
+const { __decorate: _decorate } = require("tslib");
+const { Injectable } = require("lib");
+let Service = class Service {
+	run() {}
+};
+_decorate([Injectable()], Service.prototype, "run", null);
+Service = _decorate([Injectable()], Service);
+module.exports = Service;
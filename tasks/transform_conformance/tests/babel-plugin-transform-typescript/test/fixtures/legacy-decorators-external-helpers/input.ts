@@ -0,0 +1,9 @@
+import { Injectable, Inject } from "lib";
+
+@Injectable()
+export class Service {
+  constructor(@Inject("token") token: string) {}
+
+  @Injectable()
+  run() {}
+}
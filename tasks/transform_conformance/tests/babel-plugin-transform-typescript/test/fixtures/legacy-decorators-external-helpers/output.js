@@ -0,0 +1,8 @@
+import { __decorate as _decorate, __param as _param, __metadata as _metadata } from "tslib";
+import { Injectable, Inject } from "lib";
+export let Service = class Service {
+	constructor(token) {}
+	run() {}
+};
+_decorate([Injectable(), _metadata("design:type", Function), _metadata("design:paramtypes", []), _metadata("design:returntype", void 0)], Service.prototype, "run", null);
+Service = _decorate([Injectable(), _param(0, Inject("token")), _metadata("design:paramtypes", [String])], Service);
@@ -0,0 +1,5 @@
+class C {
+  @dec #x;
+  @dec #m(@dec a) {}
+  @dec y;
+}
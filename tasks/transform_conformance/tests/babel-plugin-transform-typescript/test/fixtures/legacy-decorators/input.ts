@@ -0,0 +1,45 @@
+declare const dec: any;
+declare function key(): string;
+
+@dec
+class Foo {
+  constructor(@dec a: string) {}
+
+  @dec
+  prop: string;
+
+  @dec
+  method(a: string, @dec b: number) {}
+
+  @dec
+  static staticMethod() {}
+
+  @dec
+  get getter() {
+    return 1;
+  }
+
+  @dec
+  accessor auto = 1;
+
+  @dec
+  [key()]() {}
+
+  undecorated() {}
+}
+
+class Bar {
+  @dec
+  method() {}
+}
+
+@dec
+export class Baz {}
+
+export class Qux {
+  @dec
+  prop = 1;
+}
+
+@dec
+export default class {}
@@ -0,0 +1,44 @@
+function _decorate(decorators, target, key, desc) {
+	var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+	if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+	return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _param(paramIndex, decorator) {
+	return function(target, key) {
+		decorator(target, key, paramIndex);
+	};
+}
+var _key;
+let Foo = class Foo {
+	constructor(a) {}
+	prop;
+	method(a, b) {}
+	static staticMethod() {}
+	get getter() {
+		return 1;
+	}
+	accessor auto = 1;
+	[_key = key()]() {}
+	undecorated() {}
+};
+_decorate([dec], Foo.prototype, "prop", void 0);
+_decorate([dec, _param(1, dec)], Foo.prototype, "method", null);
+_decorate([dec], Foo.prototype, "getter", null);
+_decorate([dec], Foo.prototype, "auto", null);
+_decorate([dec], Foo.prototype, _key, null);
+_decorate([dec], Foo, "staticMethod", null);
+Foo = _decorate([dec, _param(0, dec)], Foo);
+class Bar {
+	method() {}
+}
+_decorate([dec], Bar.prototype, "method", null);
+export let Baz = class Baz {};
+Baz = _decorate([dec], Baz);
+export class Qux {
+	prop = 1;
+}
+_decorate([dec], Qux.prototype, "prop", void 0);
+let _default = class {};
+_default = _decorate([dec], _default);
+export default _default;
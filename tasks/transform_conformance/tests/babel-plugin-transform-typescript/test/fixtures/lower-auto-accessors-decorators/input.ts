@@ -0,0 +1,9 @@
+declare const dec: any;
+
+class Foo {
+  @dec
+  accessor x = 1;
+
+  @dec
+  static accessor y = 2;
+}
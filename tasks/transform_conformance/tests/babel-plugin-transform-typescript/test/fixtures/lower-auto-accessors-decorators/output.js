@@ -0,0 +1,24 @@
+function _decorate(decorators, target, key, desc) {
+	var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+	if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+	return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+class Foo {
+	#x_accessor_storage = 1;
+	get x() {
+		return this.#x_accessor_storage;
+	}
+	set x(value) {
+		this.#x_accessor_storage = value;
+	}
+	static #y_accessor_storage = 2;
+	static get y() {
+		return this.#y_accessor_storage;
+	}
+	static set y(value) {
+		this.#y_accessor_storage = value;
+	}
+}
+_decorate([dec], Foo.prototype, "x", null);
+_decorate([dec], Foo, "y", null);
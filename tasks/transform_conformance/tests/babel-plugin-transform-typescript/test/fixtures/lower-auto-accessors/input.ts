@@ -0,0 +1,14 @@
+declare function key(): string;
+
+export class Foo {
+  accessor x: number = 1;
+  static accessor y;
+  accessor #z = "z";
+  accessor "quoted" = 2;
+  accessor [key()] = 3;
+  #x_accessor_storage = 0;
+}
+
+abstract class Bar {
+  abstract accessor x: number;
+}
@@ -0,0 +1,40 @@
+var _key;
+export class Foo {
+	#x_accessor_storage_2 = 1;
+	get x() {
+		return this.#x_accessor_storage_2;
+	}
+	set x(value) {
+		this.#x_accessor_storage_2 = value;
+	}
+	static #y_accessor_storage;
+	static get y() {
+		return this.#y_accessor_storage;
+	}
+	static set y(value) {
+		this.#y_accessor_storage = value;
+	}
+	#z_accessor_storage = "z";
+	get #z() {
+		return this.#z_accessor_storage;
+	}
+	set #z(value) {
+		this.#z_accessor_storage = value;
+	}
+	#accessor_storage = 2;
+	get "quoted"() {
+		return this.#accessor_storage;
+	}
+	set "quoted"(value) {
+		this.#accessor_storage = value;
+	}
+	#_key_accessor_storage = 3;
+	get [_key = key()]() {
+		return this.#_key_accessor_storage;
+	}
+	set [_key](value) {
+		this.#_key_accessor_storage = value;
+	}
+	#x_accessor_storage = 0;
+}
+class Bar {}
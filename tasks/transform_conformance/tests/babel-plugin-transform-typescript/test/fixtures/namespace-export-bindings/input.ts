@@ -0,0 +1,12 @@
+namespace N {
+  const _N = "local";
+  const N = "shadow";
+  export const x = 1, y = x + 1;
+  export const [first, second] = [_N, N];
+  export function f() {
+    return x + y;
+  }
+  export class K {
+    value = f();
+  }
+}
@@ -0,0 +1,16 @@
+let N;
+(function(_N2) {
+	const _N = "local";
+	const N = "shadow";
+	const x = _N2.x = 1, y = _N2.y = x + 1;
+	const [first, second] = [_N, N];
+	_N2.first = first, _N2.second = second;
+	function f() {
+		return x + y;
+	}
+	_N2.f = f;
+	class K {
+		value = f();
+	}
+	_N2.K = K;
+})(N || (N = {}));
@@ -0,0 +1,4 @@
+namespace N {
+  export * from "./mod";
+  export { a } from "./a";
+}
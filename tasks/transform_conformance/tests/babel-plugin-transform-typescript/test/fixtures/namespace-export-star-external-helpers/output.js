@@ -0,0 +1,7 @@
+const { __createBinding: _createBinding, __exportStar: _exportStar } = require("tslib");
+let N;
+(function(_N) {
+	_exportStar(require("./mod"), _N);
+	var _A = require("./a");
+	_createBinding(_N, _A, "a");
+})(N || (N = {}));
@@ -0,0 +1,9 @@
+namespace N {
+  export * from "./mod";
+  export * as ns from "./ns";
+  export { a, b as c } from "./a";
+  export { type T, d } from "./d";
+  export type { U } from "./u";
+  export type * from "./types";
+  export const value = 1;
+}
@@ -0,0 +1,22 @@
+function _createBinding(o, m, k, k2) {
+	Object.defineProperty(o, k2 === void 0 ? k : k2, {
+		enumerable: true,
+		get: function() {
+			return m[k];
+		}
+	});
+}
+function _exportStar(m, o) {
+	for (var p in m) if (p !== "default" && !Object.prototype.hasOwnProperty.call(o, p)) _createBinding(o, m, p);
+}
+let N;
+(function(_N) {
+	_exportStar(require("./mod"), _N);
+	_N.ns = require("./ns");
+	var _A = require("./a");
+	_createBinding(_N, _A, "a");
+	_createBinding(_N, _A, "b", "c");
+	var _D = require("./d");
+	_createBinding(_N, _D, "d");
+	const value = _N.value = 1;
+})(N || (N = {}));
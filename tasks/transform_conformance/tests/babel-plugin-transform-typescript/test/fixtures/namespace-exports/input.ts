@@ -0,0 +1,17 @@
+namespace Shapes {
+  const scale = 2;
+
+  export const unit = 1;
+  export function area(width: number, height: number): number {
+    return width * height * scale;
+  }
+  export class Square {
+    constructor(public size: number) {}
+  }
+}
+
+declare namespace Ambient {
+  export const value: number;
+}
+
+console.log(Shapes.unit, Shapes.area(1, 2), new Shapes.Square(3).size);
@@ -0,0 +1,16 @@
+let Shapes;
+(function(_Shapes) {
+	const scale = 2;
+	const unit = _Shapes.unit = 1;
+	function area(width, height) {
+		return width * height * scale;
+	}
+	_Shapes.area = area;
+	class Square {
+		constructor(size) {
+			this.size = size;
+		}
+	}
+	_Shapes.Square = Square;
+})(Shapes || (Shapes = {}));
+console.log(Shapes.unit, Shapes.area(1, 2), new Shapes.Square(3).size);
@@ -0,0 +1,18 @@
+namespace Very.Long.Path {
+  export const value = 1;
+}
+
+namespace Foo {
+  export import Shortcut = Very.Long.Path;
+  import Local = Very.Long;
+  export const sum = Shortcut.value + Local.Path.value;
+}
+
+namespace Types {
+  export interface Foo {}
+}
+
+namespace Elided {
+  export import Foo = Types.Foo;
+  import type Bar = Very.Long.Path;
+}
@@ -0,0 +1,17 @@
+let Very;
+(function(_Very) {
+	let Long;
+	(function(_Long) {
+		let Path;
+		(function(_Path) {
+			const value = _Path.value = 1;
+		})(Path || (Path = _Long.Path || (_Long.Path = {})));
+	})(Long || (Long = _Very.Long || (_Very.Long = {})));
+})(Very || (Very = {}));
+let Foo;
+(function(_Foo) {
+	var Shortcut = Very.Long.Path;
+	_Foo.Shortcut = Shortcut;
+	var Local = Very.Long;
+	const sum = _Foo.sum = Shortcut.value + Local.Path.value;
+})(Foo || (Foo = {}));
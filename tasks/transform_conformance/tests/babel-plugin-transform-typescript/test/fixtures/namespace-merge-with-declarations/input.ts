@@ -0,0 +1,21 @@
+function fmt() {}
+namespace fmt {
+  export const width = 80;
+}
+
+class C {}
+namespace C {
+  export const kind = "class";
+}
+
+enum E {
+  A,
+}
+namespace E {
+  export function helper() {}
+}
+
+export function exportedFn() {}
+export namespace exportedFn {
+  export const meta = true;
+}
@@ -0,0 +1,20 @@
+function fmt() {}
+(function(_fmt) {
+	const width = _fmt.width = 80;
+})(fmt || (fmt = {}));
+class C {}
+(function(_C) {
+	const kind = _C.kind = "class";
+})(C || (C = {}));
+var E = function(E) {
+	E[E["A"] = 0] = "A";
+	return E;
+}(E || {});
+(function(_E) {
+	function helper() {}
+	_E.helper = helper;
+})(E || (E = {}));
+export function exportedFn() {}
+(function(_exportedFn) {
+	const meta = _exportedFn.meta = true;
+})(exportedFn || (exportedFn = {}));
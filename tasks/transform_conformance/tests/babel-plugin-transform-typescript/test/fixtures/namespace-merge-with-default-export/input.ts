@@ -0,0 +1,4 @@
+export default function format() {}
+namespace format {
+  export const width = 80;
+}
@@ -0,0 +1,4 @@
+export default function format() {}
+(function(_format) {
+	const width = _format.width = 80;
+})(format || (format = {}));
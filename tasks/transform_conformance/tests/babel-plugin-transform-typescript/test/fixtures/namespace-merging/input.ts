@@ -0,0 +1,23 @@
+declare namespace Lib {
+  export function ambient(): void;
+}
+
+namespace Lib {
+  export function a() {}
+}
+
+const between = 1;
+
+namespace Lib {
+  export function b() {}
+}
+
+export namespace Exported {
+  export const x = 1;
+}
+
+console.log(between);
+
+export namespace Exported {
+  export const y = 2;
+}
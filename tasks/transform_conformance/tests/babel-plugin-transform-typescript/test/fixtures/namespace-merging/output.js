@@ -0,0 +1,18 @@
+let Lib;
+(function(_Lib) {
+	function a() {}
+	_Lib.a = a;
+})(Lib || (Lib = {}));
+const between = 1;
+(function(_Lib2) {
+	function b() {}
+	_Lib2.b = b;
+})(Lib || (Lib = {}));
+export let Exported;
+(function(_Exported) {
+	const x = _Exported.x = 1;
+})(Exported || (Exported = {}));
+console.log(between);
+(function(_Exported2) {
+	const y = _Exported2.y = 2;
+})(Exported || (Exported = {}));
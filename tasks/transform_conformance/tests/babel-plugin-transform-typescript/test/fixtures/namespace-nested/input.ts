@@ -0,0 +1,18 @@
+module Outer {
+  export namespace Inner {
+    export const depth = 2;
+  }
+  namespace Private {
+    export const hidden = true;
+  }
+}
+
+namespace A.B.C {
+  export const path = "A.B.C";
+}
+
+declare namespace Ambient {
+  export namespace Nested {
+    export const value: number;
+  }
+}
@@ -0,0 +1,21 @@
+let Outer;
+(function(_Outer) {
+	let Inner;
+	(function(_Inner) {
+		const depth = _Inner.depth = 2;
+	})(Inner || (Inner = _Outer.Inner || (_Outer.Inner = {})));
+	let Private;
+	(function(_Private) {
+		const hidden = _Private.hidden = true;
+	})(Private || (Private = {}));
+})(Outer || (Outer = {}));
+let A;
+(function(_A) {
+	let B;
+	(function(_B) {
+		let C;
+		(function(_C) {
+			const path = _C.path = "A.B.C";
+		})(C || (C = _B.C || (_B.C = {})));
+	})(B || (B = _A.B || (_A.B = {})));
+})(A || (A = {}));
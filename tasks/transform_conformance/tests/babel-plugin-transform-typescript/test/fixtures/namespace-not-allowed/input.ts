@@ -0,0 +1,11 @@
+namespace Types {
+  export type T = string;
+}
+
+declare namespace Ambient {
+  const x: number;
+}
+
+export namespace Values.Nested {
+  export const x = 1;
+}
@@ -0,0 +1,31 @@
+namespace Types {
+  export interface Foo {}
+  export type Bar = string;
+}
+
+export namespace ExportedTypes {
+  interface Baz {}
+}
+
+namespace Alias {
+  type T = number;
+  export import A = Types.Foo;
+}
+
+namespace Outer {
+  export namespace Inner {
+    export type Id = string;
+  }
+  namespace Deep.Deeper {
+    interface I {}
+  }
+  declare const ambient: number;
+  declare namespace Ambient {
+    export const x: number;
+  }
+}
+
+namespace Mixed {
+  export namespace Empty {}
+  export const value = 1;
+}
@@ -0,0 +1,5 @@
+let Mixed;
+(function(_Mixed) {
+	const value = _Mixed.value = 1;
+})(Mixed || (Mixed = {}));
+export {};
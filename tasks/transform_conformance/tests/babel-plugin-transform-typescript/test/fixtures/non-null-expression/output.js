@@ -0,0 +1,11 @@
+foo.bar();
+foo.bar();
+const first = arr[i];
+a++;
+--b.c;
+d = 1;
+e.f += 2;
+g?.h;
+[j, k.l] = [1, 2];
+({m: n} = { m: 3 });
+for (o of list) {}
@@ -0,0 +1,9 @@
+import type { A } from "a";
+import { type B, C } from "b";
+import { type D } from "d";
+import E, { F } from "e";
+import * as G from "g";
+
+export type { H } from "h";
+
+const value: A & B & D = C;
@@ -0,0 +1,2 @@
+import { C } from "b";
+const value = C;
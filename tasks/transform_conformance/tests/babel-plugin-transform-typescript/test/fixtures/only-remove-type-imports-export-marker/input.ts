@@ -0,0 +1,4 @@
+import type { A } from "a";
+export type { B } from "b";
+
+const value: A = {};
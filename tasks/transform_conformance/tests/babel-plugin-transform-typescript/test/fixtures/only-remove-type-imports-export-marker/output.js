@@ -0,0 +1,2 @@
+const value = {};
+export {};
@@ -0,0 +1,8 @@
+import Default, { Props, type State, value, mixed, unused } from "./mod";
+import * as ns from "./ns";
+
+let props: Props;
+let state: State;
+let copy: typeof mixed = mixed;
+
+console.log(Default, value, ns);
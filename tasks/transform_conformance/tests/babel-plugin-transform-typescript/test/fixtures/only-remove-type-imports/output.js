@@ -0,0 +1,5 @@
+import { C } from "b";
+import "d";
+import E, { F } from "e";
+import * as G from "g";
+const value = C;
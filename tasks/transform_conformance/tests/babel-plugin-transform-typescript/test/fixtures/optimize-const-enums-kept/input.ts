@@ -0,0 +1,24 @@
+export const enum Direction {
+  Up,
+  Down,
+  "Left-Right" = "left-right",
+}
+
+const enum Color {
+  Red = "red",
+  Green = "green",
+}
+
+declare const enum Ambient {
+  A = 10,
+}
+
+declare const key: "Red" | "Green";
+
+const enum Size {
+  Small = 1,
+}
+
+let size: Size = Size.Small;
+
+console.log(Direction.Up, Direction["Left-Right"], Color.Red, Color[key], Ambient.A);
@@ -0,0 +1,11 @@
+export let Direction = {
+	Up: 0,
+	Down: 1,
+	"Left-Right": "left-right"
+};
+var Color = {
+	Red: "red",
+	Green: "green"
+};
+let size = 1;
+console.log(0, "left-right", "red", Color[key], 10);
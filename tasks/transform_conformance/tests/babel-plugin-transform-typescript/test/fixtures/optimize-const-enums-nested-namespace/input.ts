@@ -0,0 +1,23 @@
+namespace Outer {
+  export const enum Inner { A = 1, B = A + 1 }
+  export namespace Deep {
+    export const enum E { X = "x" }
+  }
+  Inner.B;
+}
+const a = Outer.Inner.A;
+const b = Outer.Deep.E.X;
+const c = Outer.Inner;
+const d = Outer.Inner["B"];
+
+namespace A.B {
+  export const enum C { X = 10 }
+}
+const e = A.B.C.X;
+
+declare namespace Ambient {
+  export namespace Nested {
+    const enum E { Y = 20 }
+  }
+}
+const f = Ambient.Nested.E.Y;
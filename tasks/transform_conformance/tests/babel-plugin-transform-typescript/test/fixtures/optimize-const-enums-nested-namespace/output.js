@@ -0,0 +1,28 @@
+let Outer;
+(function(_Outer) {
+	let Inner = {
+		A: 1,
+		B: 2
+	};
+	_Outer.Inner = Inner;
+	let Deep;
+	(function(_Deep) {
+		let E = { X: "x" };
+		_Deep.E = E;
+	})(Deep || (Deep = _Outer.Deep || (_Outer.Deep = {})));
+	2;
+})(Outer || (Outer = {}));
+const a = 1;
+const b = "x";
+const c = Outer.Inner;
+const d = 2;
+let A;
+(function(_A) {
+	let B;
+	(function(_B) {
+		let C = { X: 10 };
+		_B.C = C;
+	})(B || (B = _A.B || (_A.B = {})));
+})(A || (A = {}));
+const e = 10;
+const f = 20;
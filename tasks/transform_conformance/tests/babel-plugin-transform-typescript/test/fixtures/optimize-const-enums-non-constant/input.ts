@@ -0,0 +1,14 @@
+declare const value: number;
+
+const enum Flags {
+  None = 0,
+  A = 1 << 0,
+  B = ~A & 0xff,
+  C = (A | B) ^ (16 >>> 2) % 3,
+  D = value,
+  E = Math.random() * 2,
+  F,
+  G = "g" + D,
+}
+
+Flags.A;
@@ -0,0 +1,12 @@
+function getWidth() {
+  return Size.Large;
+}
+
+const enum Size {
+  Small = 1,
+  Medium,
+  Large = Medium * 2,
+  Label = "size",
+}
+
+console.log(Size.Small, Size["Medium"], Size.Label, Size.Unknown);
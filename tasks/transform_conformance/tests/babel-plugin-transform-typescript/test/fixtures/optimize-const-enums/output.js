@@ -0,0 +1,10 @@
+function getWidth() {
+	return 4;
+}
+var Size = {
+	Small: 1,
+	Medium: 2,
+	Large: 4,
+	Label: "size"
+};
+console.log(1, 2, "size", Size.Unknown);
@@ -0,0 +1,17 @@
+export let Direction = function(Direction) {
+	Direction[Direction["Up"] = 0] = "Up";
+	Direction[Direction["Down"] = 1] = "Down";
+	Direction["Left-Right"] = "left-right";
+	return Direction;
+}({});
+var Color = function(Color) {
+	Color["Red"] = "red";
+	Color["Green"] = "green";
+	return Color;
+}(Color || {});
+var Size = function(Size) {
+	Size[Size["Small"] = 1] = "Small";
+	return Size;
+}(Size || {});
+let size = Size.Small;
+console.log(Direction.Up, Direction["Left-Right"], Color.Red, Color[key], 10);
@@ -0,0 +1,9 @@
+import { helper } from "./helper";
+interface Local {}
+const value = 1;
+
+export { Props, type State, value, helper, Local as L } from "./mod";
+export type { Other } from "./mod";
+export * from "./mod";
+export * as ns from "./mod";
+export { value as default };
@@ -0,0 +1,7 @@
+import a = require("./a.ts");
+import b = require("./b.cts?raw");
+import type c = require("./c.ts");
+export import d = require("./d.mts");
+import e = require("pkg.ts");
+
+a(b, e);
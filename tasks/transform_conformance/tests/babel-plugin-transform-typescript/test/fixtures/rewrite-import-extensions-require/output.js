@@ -0,0 +1,5 @@
+var a = require("./a.js");
+var b = require("./b.cjs?raw");
+var d = exports.d = require("./d.mjs");
+var e = require("pkg.ts");
+a(b, e);
@@ -0,0 +1,18 @@
+import { a } from "./a.ts";
+import b from "../b.mts";
+import * as c from "./c.cts";
+import "./d.tsx";
+import raw from "./e.ts?raw";
+import url from "./f.ts#hash";
+import "https://esm.sh/g.ts";
+import "pkg.ts";
+import "./h.js";
+export { i } from "./i.ts";
+export * from "./j.mts";
+export * as k from "./k.ts?v=1#top";
+
+import("./l.ts");
+import(`./m.ts`);
+import("pkg.ts");
+
+console.log(a, b, c, raw, url);
@@ -0,0 +1,16 @@
+import { a } from "./a.js";
+import b from "../b.mjs";
+import * as c from "./c.cjs";
+import "./d.js";
+import raw from "./e.js?raw";
+import url from "./f.js#hash";
+import "https://esm.sh/g.ts";
+import "pkg.ts";
+import "./h.js";
+export { i } from "./i.js";
+export * from "./j.mjs";
+export * as k from "./k.js?v=1#top";
+import("./l.js");
+import(`./m.ts`);
+import("pkg.ts");
+console.log(a, b, c, raw, url);
@@ -0,0 +1,19 @@
+let a: number = 1;
+let b!: string;
+const { c }: { c: number } = { c: 1 };
+function f<T extends object>(x: T, y?: number, ...rest: string[]): T {
+  return x;
+}
+const g = <T,>(x: T): T => x;
+const h = function <T>(this: unknown, x?: T): void {};
+class Foo<T> extends Bar<T> implements Baz {
+  x: number = 1;
+  y?: string;
+  z!: boolean;
+  method<U>(u: U): U {
+    return u;
+  }
+}
+const call = f<object>({}, 1);
+const instance = new Foo<number>();
+function opt({ a }: { a?: number } = {}, [b]: number[] = []) {}
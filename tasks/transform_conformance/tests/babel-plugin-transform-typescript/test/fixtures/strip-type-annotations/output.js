@@ -0,0 +1,19 @@
+let a = 1;
+let b;
+const { c } = { c: 1 };
+function f(x, y, ...rest) {
+	return x;
+}
+const g = (x) => x;
+const h = function(x) {};
+class Foo extends Bar {
+	x = 1;
+	y;
+	z;
+	method(u) {
+		return u;
+	}
+}
+const call = f({}, 1);
+const instance = new Foo();
+function opt({ a } = {}, [b] = []) {}
@@ -0,0 +1,17 @@
+function f(this: Window, a: number, ...rest: string[]) {
+  return this;
+}
+
+const obj = {
+  m(this: typeof obj, x = 1) {
+    return x;
+  },
+};
+
+class C {
+  m(this: C, [a]: number[]) {}
+  set value(this: C, v: number) {}
+}
+
+declare function g(this: void): void;
+export default function (this: unknown) {}
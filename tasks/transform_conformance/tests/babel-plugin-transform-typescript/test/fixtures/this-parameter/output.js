@@ -0,0 +1,11 @@
+function f(a, ...rest) {
+	return this;
+}
+const obj = { m(x = 1) {
+	return x;
+} };
+class C {
+	m([a]) {}
+	set value(v) {}
+}
+export default function() {}
@@ -0,0 +1,14 @@
+const a = <number>value;
+const b = (<Function>fn)();
+const c = !<boolean>flag;
+const d = (<any>obj).prop;
+const e = new (<any>Ctor)();
+let f;
+(<any>f) = 1;
+const g = <string>(<unknown>value);
+const h = typeof <any>value;
+const i = (<any>x) + 1;
+const j = (<any>(a, b)) * 2;
+(<any>(() => 1))();
+(<any>{ key: 1 }).key;
+(<any>function () {})();
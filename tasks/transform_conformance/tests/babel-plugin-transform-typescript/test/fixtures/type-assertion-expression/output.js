@@ -0,0 +1,14 @@
+const a = value;
+const b = fn();
+const c = !flag;
+const d = obj.prop;
+const e = new Ctor();
+let f;
+f = 1;
+const g = value;
+const h = typeof value;
+const i = x + 1;
+const j = (a, b) * 2;
+(() => 1)();
+({ key: 1 }).key;
+(function() {})();